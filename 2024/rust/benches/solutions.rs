@@ -1,86 +1,75 @@
-use aoc24::get_input;
+use aoc24::{get_input, Day, Solution, SOLUTIONS};
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
 /*-------------------------------------------------------------------------------------------------
   Solution Benchmarks
 -------------------------------------------------------------------------------------------------*/
 
-/*--------------------------------------------------------------------------------------
-  Macro
---------------------------------------------------------------------------------------*/
+// Benchmarks are grouped by day (`day5/parse`, `day5/part1`, ...), so criterion's own filter
+// selects them by name: `cargo bench -- day5/`. Set `AOC_BENCH_DAYS` to a comma-separated list of
+// days (like `AOC_BENCH_DAYS=5,7`) to skip loading the other days' inputs altogether.
+const DAYS_ENV: &str = "AOC_BENCH_DAYS";
 
-macro_rules! bench_solution {
-    ($c:ident, $solution:path, $input:literal) => {
-        let input = get_input($input);
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
 
-        $c.bench_function(stringify!($solution), |b| {
-            b.iter(|| $solution(black_box(&input)));
-        });
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let days = selected_days();
+
+    // Benchmark all registered solutions
+    for solution in SOLUTIONS {
+        if days
+            .as_ref()
+            .is_some_and(|days| !days.contains(&solution.day()))
+        {
+            continue;
+        }
+
+        bench_solution(c, *solution);
+    }
+}
+
+fn bench_solution(c: &mut Criterion, solution: &dyn Solution) {
+    let input = get_input(&format!("day{}/input.txt", solution.day())).and_then(|input| {
+        let parsed_input = solution.parse(&input)?;
+        Ok((input, parsed_input))
+    });
+
+    let (input, parsed_input) = match input {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("Skipping {}: {error:#}", solution.name());
+            return;
+        }
     };
+    let parameters = solution.default_parameters();
+
+    let mut group = c.benchmark_group(solution.name());
 
-    ($c:ident, $solution:path, $input:literal, $($args:expr),*) => {
-        let input = get_input($input);
+    group.bench_function("parse", |b| {
+        b.iter(|| solution.parse(black_box(&input)));
+    });
 
-        $c.bench_function(stringify!($solution), |b| {
-            b.iter(|| $solution(black_box(&input), $($args),*));
+    // Parts are benchmarked from the parsed input, so they measure solving alone
+    for part in solution.parts() {
+        group.bench_function(part.to_string(), |b| {
+            b.iter(|| solution.solve(*part, black_box(&parsed_input), &parameters));
         });
-    };
-}
+    }
 
-/*--------------------------------------------------------------------------------------
-  Benchmarks
---------------------------------------------------------------------------------------*/
+    group.finish();
+}
 
-criterion_group!(benches, criterion_benchmark);
-criterion_main!(benches);
+fn selected_days() -> Option<Vec<Day>> {
+    let days = std::env::var(DAYS_ENV).ok()?;
 
-pub fn criterion_benchmark(c: &mut Criterion) {
-    // Benchmark all solutions
-    bench_solution!(c, aoc24::day1::part1, "../data/day1/input.txt");
-    bench_solution!(c, aoc24::day1::part2, "../data/day1/input.txt");
-    bench_solution!(c, aoc24::day2::part1, "../data/day2/input.txt");
-    bench_solution!(c, aoc24::day2::part2, "../data/day2/input.txt");
-    bench_solution!(c, aoc24::day3::part1, "../data/day3/input.txt");
-    bench_solution!(c, aoc24::day3::part2, "../data/day3/input.txt");
-    bench_solution!(c, aoc24::day4::part1, "../data/day4/input.txt");
-    bench_solution!(c, aoc24::day4::part2, "../data/day4/input.txt");
-    bench_solution!(c, aoc24::day5::part1, "../data/day5/input.txt");
-    bench_solution!(c, aoc24::day5::part2, "../data/day5/input.txt");
-    bench_solution!(c, aoc24::day6::part1, "../data/day6/input.txt");
-    bench_solution!(c, aoc24::day6::part2, "../data/day6/input.txt");
-    bench_solution!(c, aoc24::day7::part1, "../data/day7/input.txt");
-    bench_solution!(c, aoc24::day7::part2, "../data/day7/input.txt");
-    bench_solution!(c, aoc24::day8::part1, "../data/day8/input.txt");
-    bench_solution!(c, aoc24::day8::part2, "../data/day8/input.txt");
-    bench_solution!(c, aoc24::day9::part1, "../data/day9/input.txt");
-    bench_solution!(c, aoc24::day9::part2, "../data/day9/input.txt");
-    bench_solution!(c, aoc24::day10::part1, "../data/day10/input.txt");
-    bench_solution!(c, aoc24::day10::part2, "../data/day10/input.txt");
-    bench_solution!(c, aoc24::day11::part1, "../data/day11/input.txt");
-    bench_solution!(c, aoc24::day11::part2, "../data/day11/input.txt");
-    bench_solution!(c, aoc24::day12::part1, "../data/day12/input.txt");
-    bench_solution!(c, aoc24::day12::part2, "../data/day12/input.txt");
-    bench_solution!(c, aoc24::day13::part1, "../data/day13/input.txt");
-    bench_solution!(c, aoc24::day13::part2, "../data/day13/input.txt");
-    bench_solution!(c, aoc24::day14::part1, "../data/day14/input.txt", 101, 103);
-    bench_solution!(c, aoc24::day14::part2, "../data/day14/input.txt", false);
-    bench_solution!(c, aoc24::day15::part1, "../data/day15/input.txt");
-    bench_solution!(c, aoc24::day15::part2, "../data/day15/input.txt");
-    bench_solution!(c, aoc24::day16::part1, "../data/day16/input.txt");
-    bench_solution!(c, aoc24::day16::part2, "../data/day16/input.txt");
-    bench_solution!(c, aoc24::day17::part1, "../data/day17/input.txt");
-    bench_solution!(c, aoc24::day17::part2, "../data/day17/input.txt");
-    bench_solution!(c, aoc24::day18::part1, "../data/day18/input.txt");
-    bench_solution!(c, aoc24::day18::part2, "../data/day18/input.txt");
-    bench_solution!(c, aoc24::day19::part1, "../data/day19/input.txt");
-    bench_solution!(c, aoc24::day19::part2, "../data/day19/input.txt");
-    bench_solution!(c, aoc24::day20::part1, "../data/day20/input.txt");
-    bench_solution!(c, aoc24::day20::part2, "../data/day20/input.txt");
-    bench_solution!(c, aoc24::day21::part1, "../data/day21/input.txt");
-    bench_solution!(c, aoc24::day21::part2, "../data/day21/input.txt");
-    bench_solution!(c, aoc24::day22::part1, "../data/day22/input.txt");
-    bench_solution!(c, aoc24::day22::part2, "../data/day22/input.txt");
-    bench_solution!(c, aoc24::day23::part1, "../data/day23/input.txt");
-    bench_solution!(c, aoc24::day23::part2, "../data/day23/input.txt");
-    bench_solution!(c, aoc24::day24::part1, "../data/day24/input.txt");
+    Some(
+        days.split(',')
+            .map(|day| {
+                day.trim()
+                    .parse()
+                    .unwrap_or_else(|_| panic!("Invalid day in {DAYS_ENV}: {day:?}"))
+            })
+            .collect(),
+    )
 }
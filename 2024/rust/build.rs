@@ -0,0 +1,83 @@
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[path = "src/shared/answer_files.rs"]
+mod answer_files;
+use answer_files::answer_cases;
+
+/*-------------------------------------------------------------------------------------------------
+  Data Test Generation
+-------------------------------------------------------------------------------------------------*/
+
+// Generates one test for every non-empty `<stem>-partN-answer.txt` file in the data directory,
+// paired with its `<stem>-partN.txt` or `<stem>.txt` input. A `<stem>-partN.slow` or `<stem>.slow`
+// marker file limits the test to the `slow_tests` feature.
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/shared/answer_files.rs");
+    println!("cargo:rerun-if-env-changed=AOC_DATA_DIR");
+
+    let data_dir = env::var_os("AOC_DATA_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("../data"));
+    println!("cargo:rerun-if-changed={}", data_dir.display());
+
+    let mut tests = String::new();
+    for (day, day_dir) in day_dirs(&data_dir) {
+        for case in answer_cases(&day_dir) {
+            let Some(input_file) = case.input_file else {
+                println!(
+                    "cargo:warning=No input file for day{day}/{}",
+                    case.answer_file
+                );
+                continue;
+            };
+
+            writeln!(tests, "#[test]").unwrap();
+            if case.slow {
+                writeln!(tests, "#[cfg_attr(not(feature = \"slow_tests\"), ignore)]").unwrap();
+            }
+            writeln!(
+                tests,
+                "fn day{day}_{name}_part{part}() {{\n    \
+                     check_answer({day}, Part::Part{part}, \"day{day}/{input_file}\");\n\
+                 }}\n",
+                name = test_name(&case.stem),
+                part = case.part,
+            )
+            .unwrap();
+        }
+    }
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_dir.join("data_tests.rs"), tests).unwrap();
+}
+
+fn day_dirs(data_dir: &Path) -> Vec<(u8, PathBuf)> {
+    let mut day_dirs: Vec<(u8, PathBuf)> = fs::read_dir(data_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| {
+            let day = entry
+                .file_name()
+                .to_str()?
+                .strip_prefix("day")?
+                .parse()
+                .ok()?;
+            entry.path().is_dir().then(|| (day, entry.path()))
+        })
+        .collect();
+
+    day_dirs.sort();
+    day_dirs
+}
+
+fn test_name(stem: &str) -> String {
+    stem.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
@@ -1,14 +1,15 @@
-use crate::get_input;
+use crate::{Answer, Day, InputParser, Parameters, ParsedInput, Solution};
+use anyhow::Result;
 use std::collections::HashMap;
 use std::iter::zip;
-use std::path::PathBuf;
 
 /*-------------------------------------------------------------------------------------------------
   Day 1: Historian Hysteria
 -------------------------------------------------------------------------------------------------*/
 
-pub fn part1(input: &str) -> Option<String> {
-    let (mut left_list, mut right_list) = parse_input(input);
+pub fn part1(input: &Input) -> Result<Answer> {
+    let mut left_list = input.left_list.clone();
+    let mut right_list = input.right_list.clone();
 
     left_list.sort();
     right_list.sort();
@@ -17,62 +18,88 @@ pub fn part1(input: &str) -> Option<String> {
         .map(|(left, right)| (right - left).abs())
         .sum();
 
-    Some(total_distance.to_string())
+    Ok(total_distance.into())
 }
 
-pub fn part2(input: &str) -> Option<String> {
-    let (left_list, right_list) = parse_input(input);
-
+pub fn part2(input: &Input) -> Result<Answer> {
     let right_list_id_count: HashMap<LocationID, IdCount> =
-        right_list.iter().fold(HashMap::new(), |mut acc, id| {
+        input.right_list.iter().fold(HashMap::new(), |mut acc, id| {
             *acc.entry(*id).or_insert(0) += 1;
             acc
         });
 
-    let similarity_score: SimilarityScore = left_list
+    let similarity_score: SimilarityScore = input
+        .left_list
         .iter()
         .map(|value| value * *right_list_id_count.get(value).unwrap_or(&0))
         .sum();
 
-    Some(similarity_score.to_string())
+    Ok(similarity_score.into())
 }
 
 /*--------------------------------------------------------------------------------------
   Core
 --------------------------------------------------------------------------------------*/
 
+const DAY: Day = 1;
+
 type LocationID = i32;
 type IdCount = i32;
 type Distance = i32;
 type SimilarityScore = i32;
 
-fn parse_input(input: &str) -> (Vec<LocationID>, Vec<LocationID>) {
-    input
-        .lines()
-        .map(|line| {
-            let mut parts = line.split("   ");
-            let left: LocationID = parts.next().unwrap().parse().unwrap();
-            let right: LocationID = parts.next().unwrap().parse().unwrap();
-            (left, right)
+#[derive(Debug)]
+pub struct Input {
+    left_list: Vec<LocationID>,
+    right_list: Vec<LocationID>,
+}
+
+impl Input {
+    pub fn parse(input: &str) -> Result<Self> {
+        let parser = InputParser::new(DAY, input);
+
+        let (left_list, right_list) = input
+            .lines()
+            .map(|line| {
+                let mut parts = line.split("   ");
+                let left: LocationID = parser.parse(parser.required(parts.next(), line)?)?;
+                let right: LocationID = parser.parse(parser.required(parts.next(), line)?)?;
+                Ok((left, right))
+            })
+            .collect::<Result<(Vec<_>, Vec<_>)>>()?;
+
+        Ok(Self {
+            left_list,
+            right_list,
         })
-        .unzip()
+    }
 }
 
 /*-------------------------------------------------------------------------------------------------
   CLI
 -------------------------------------------------------------------------------------------------*/
 
-#[derive(clap::Subcommand)]
-#[command(long_about = "Day 1: Historian Hysteria")]
-pub enum Args {
-    Part1 { input: PathBuf },
-    Part2 { input: PathBuf },
-}
+pub struct Day1;
+
+impl Solution for Day1 {
+    fn day(&self) -> Day {
+        DAY
+    }
+
+    fn title(&self) -> &'static str {
+        "Historian Hysteria"
+    }
+
+    fn parse(&self, input: &str) -> Result<ParsedInput> {
+        Ok(ParsedInput::new(Input::parse(input)?))
+    }
+
+    fn part1(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part1(input.get()?)
+    }
 
-pub fn main(args: Args) -> Option<String> {
-    match args {
-        Args::Part1 { input } => part1(&get_input(&input)),
-        Args::Part2 { input } => part2(&get_input(&input)),
+    fn part2(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part2(input.get()?)
     }
 }
 
@@ -83,37 +110,19 @@ pub fn main(args: Args) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::get_answer;
 
     #[test]
-    fn test_example_solution_part1() {
+    fn test_parse_input_errors() {
+        let error = Input::parse("3   4\n\n4   3\n").unwrap_err();
         assert_eq!(
-            part1(&get_input("../data/day1/example.txt")),
-            get_answer("../data/day1/example-part1-answer.txt")
+            error.to_string(),
+            "Day 1, line 2, column 1: cannot parse integer from empty string"
         );
-    }
-
-    #[test]
-    fn test_example_solution_part2() {
-        assert_eq!(
-            part2(&get_input("../data/day1/example.txt")),
-            get_answer("../data/day1/example-part2-answer.txt")
-        );
-    }
 
-    #[test]
-    fn test_part1_solution() {
-        assert_eq!(
-            part1(&get_input("../data/day1/input.txt")),
-            get_answer("../data/day1/input-part1-answer.txt")
-        );
-    }
-
-    #[test]
-    fn test_part2_solution() {
+        let error = Input::parse("3   4\n4   x\n").unwrap_err();
         assert_eq!(
-            part2(&get_input("../data/day1/input.txt")),
-            get_answer("../data/day1/input-part2-answer.txt")
+            error.to_string(),
+            "Day 1, line 2, column 5: invalid digit found in string (\"x\")"
         );
     }
 }
@@ -1,236 +1,138 @@
-use crate::get_input;
-use std::collections::HashSet;
-use std::hash::Hash;
-use std::path::PathBuf;
+use crate::{Answer, Day, GridIndex, GridMap, InputParser, Parameters, ParsedInput, Solution};
+use anyhow::Result;
+use std::cmp::Reverse;
 
 /*-------------------------------------------------------------------------------------------------
   Day 10: Hoof It
 -------------------------------------------------------------------------------------------------*/
 
-pub fn part1(input: &str) -> Option<String> {
-    let map = parse_input(input);
-    let (_, peak_count) = map_trails(&map);
+pub fn part1(input: &Input) -> Result<Answer> {
+    let peak_count: PeakCount = trailheads(&input.map)
+        .map(|trailhead| count_reachable_peaks(&input.map, trailhead))
+        .sum();
 
-    Some(peak_count.to_string())
+    Ok(peak_count.into())
 }
 
-pub fn part2(input: &str) -> Option<String> {
-    let map = parse_input(input);
-    let (trail_count, _) = map_trails(&map);
+pub fn part2(input: &Input) -> Result<Answer> {
+    let ratings = rate_trails(&input.map);
+    let trail_count: TrailCount = trailheads(&input.map)
+        .filter_map(|trailhead| ratings.get(trailhead))
+        .sum();
 
-    Some(trail_count.to_string())
+    Ok(trail_count.into())
 }
 
 /*--------------------------------------------------------------------------------------
   Core
 --------------------------------------------------------------------------------------*/
 
-fn parse_input(input: &str) -> Map {
-    let map: Vec<Vec<_>> = input
-        .lines()
-        .map(|line| line.chars().map(|c| c.to_digit(10).unwrap()).collect())
-        .collect();
-
-    Map::new(map)
-}
-
-fn map_trails(map: &Map) -> (TrailCount, PeakCount) {
-    map.get_trailheads()
-        .iter()
-        .map(|trailhead| {
-            let hiker = Hiker::new(*trailhead);
-            let (trail_count, peaks) = hike_trails(map, hiker);
-            let peak_count = peaks.len();
-            (trail_count, peak_count)
-        })
-        .reduce(|acc, value| (acc.0 + value.0, acc.1 + value.1))
-        .unwrap()
-}
-
-fn hike_trails(map: &Map, hiker: Hiker) -> (TrailCount, HashSet<Peak>) {
-    // Base cases
-    if map.get(hiker.position).unwrap() == 9 {
-        let mut peaks = HashSet::new();
-        peaks.insert(hiker.position);
-        return (1, peaks);
-    };
-
-    let available_directions = hiker.available_directions(map);
-
-    if available_directions.is_empty() {
-        return (0, HashSet::new());
-    }
-
-    // Recursive case
-    available_directions
-        .iter()
-        .fold((0, HashSet::new()), |acc, direction| {
-            let mut hiker = hiker.clone();
-            hiker.r#move(map, direction);
-            let (trail_count, peaks) = hike_trails(map, hiker);
-
-            (acc.0 + trail_count, acc.1.union(&peaks).cloned().collect())
-        })
-}
-
-/*-----------------------------------------------------------------------------
-  Map
------------------------------------------------------------------------------*/
+const DAY: Day = 10;
 
-type Height = u32;
-type Location = (usize, usize);
-type Offset = (i64, i64);
+type Index = i16;
+type Height = u8;
+type Map = GridMap<Index, Height>;
+type Location = GridIndex<Index>;
 type TrailCount = usize;
-type Peak = Location;
 type PeakCount = usize;
 
-struct Map {
-    map: Vec<Vec<Height>>,
-    rows: usize,
-    columns: usize,
-}
+const TRAILHEAD: Height = 0;
+const PEAK: Height = 9;
 
-impl Map {
-    fn new(map: Vec<Vec<Height>>) -> Self {
-        let rows = map.len();
-        let columns = map[0].len();
-        Self { map, rows, columns }
-    }
+pub struct Input {
+    map: Map,
+}
 
-    fn get_trailheads(&self) -> Vec<Location> {
-        self.map
-            .iter()
-            .enumerate()
-            .flat_map(|(row, columns)| {
-                columns
-                    .iter()
-                    .enumerate()
-                    .filter_map(|(column, &height)| {
-                        if height == 0 {
-                            Some((row, column))
-                        } else {
-                            None
-                        }
+impl Input {
+    pub fn parse(input: &str) -> Result<Self> {
+        let parser = InputParser::new(DAY, input);
+        parser.rectangular(input)?;
+
+        let rows = input
+            .lines()
+            .map(|line| {
+                line.char_indices()
+                    .map(|(index, c)| {
+                        c.to_digit(10)
+                            .map(|digit| digit as Height)
+                            .ok_or_else(|| parser.char_error(line, index, "Expected a digit"))
                     })
-                    .collect::<Vec<Location>>()
+                    .collect::<Result<Vec<_>, _>>()
             })
-            .collect()
-    }
+            .collect::<Result<Vec<_>, _>>()?;
 
-    fn get(&self, location: Location) -> Option<Height> {
-        let (row, column) = location;
-        if row < self.rows && column < self.columns {
-            Some(self.map[row][column])
-        } else {
-            None
-        }
+        Ok(Self {
+            map: rows.into_iter().map(|row| row.into_iter()).collect(),
+        })
     }
+}
 
-    fn project_location(&self, location: &Location, direction: &Direction) -> Option<Location> {
-        let (row, column) = location;
-        let (row_shift, column_shift) = direction.offset();
+fn trailheads(map: &Map) -> impl Iterator<Item = Location> + '_ {
+    map.enumerate()
+        .filter(|(_, &height)| height == TRAILHEAD)
+        .map(|(location, _)| location)
+}
 
-        let (new_row, new_column) = (*row as i64 + row_shift, *column as i64 + column_shift);
+// Hiking trails climb one height at a time, so the peaks in the trailhead's uphill flood fill are
+// the ones it has trails to
+fn count_reachable_peaks(map: &Map, trailhead: Location) -> PeakCount {
+    map.flood_fill(trailhead, |&from, &to| to == from + 1)
+        .into_iter()
+        .filter(|&location| map.get(location) == Some(&PEAK))
+        .count()
+}
 
-        let new_location = if new_row >= 0 && new_column >= 0 {
-            (new_row as usize, new_column as usize)
-        } else {
-            return None;
-        };
+// A location's rating is the number of hiking trails from it to any peak, which is the sum of its
+// uphill neighbors' ratings. Rating the locations from the peaks down means each neighbor has been
+// rated before it's needed.
+fn rate_trails(map: &Map) -> GridMap<Index, TrailCount> {
+    let mut locations: Vec<(Location, Height)> = map
+        .enumerate()
+        .map(|(location, &height)| (location, height))
+        .collect();
+    locations.sort_by_key(|&(_, height)| Reverse(height));
 
-        if new_location.0 < self.rows && new_location.1 < self.columns {
-            Some(new_location)
+    let mut ratings = GridMap::new(map.rows(), map.columns(), 0);
+    for (location, height) in locations {
+        let rating = if height == PEAK {
+            1
         } else {
-            None
-        }
+            map.neighbors(location)
+                .filter(|(_, &next_height)| next_height == height + 1)
+                .filter_map(|(next_location, _)| ratings.get(next_location))
+                .sum()
+        };
+        ratings.set(location, rating).unwrap();
     }
 
-    fn get_projected_value(&self, location: &Location, direction: &Direction) -> Option<Height> {
-        self.project_location(location, direction)
-            .and_then(|new_location| self.get(new_location))
-    }
+    ratings
 }
 
-/*-----------------------------------------------------------------------------
-  Hiker
------------------------------------------------------------------------------*/
-
-#[derive(Debug, Clone)]
-struct Hiker {
-    position: Location,
-}
+/*-------------------------------------------------------------------------------------------------
+  CLI
+-------------------------------------------------------------------------------------------------*/
 
-impl Hiker {
-    fn new(position: (usize, usize)) -> Self {
-        let mut route = HashSet::new();
-        route.insert(position);
+pub struct Day10;
 
-        Self { position }
+impl Solution for Day10 {
+    fn day(&self) -> Day {
+        DAY
     }
 
-    fn available_directions(&self, map: &Map) -> Vec<Direction> {
-        let current_height = map.get(self.position).unwrap();
-        [
-            Direction::North,
-            Direction::South,
-            Direction::East,
-            Direction::West,
-        ]
-        .iter()
-        .filter_map(|direction| {
-            let next_height = map.get_projected_value(&self.position, direction)?;
-            if next_height == current_height + 1 {
-                Some(*direction)
-            } else {
-                None
-            }
-        })
-        .collect()
+    fn title(&self) -> &'static str {
+        "Hoof It"
     }
 
-    fn r#move(&mut self, map: &Map, direction: &Direction) {
-        self.position = map.project_location(&self.position, direction).unwrap();
+    fn parse(&self, input: &str) -> Result<ParsedInput> {
+        Ok(ParsedInput::new(Input::parse(input)?))
     }
-}
-
-/*-----------------------------------------------------------------------------
-  Direction
------------------------------------------------------------------------------*/
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum Direction {
-    North,
-    South,
-    East,
-    West,
-}
-
-impl Direction {
-    fn offset(&self) -> Offset {
-        match self {
-            Direction::North => (-1, 0),
-            Direction::South => (1, 0),
-            Direction::East => (0, 1),
-            Direction::West => (0, -1),
-        }
+    fn part1(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part1(input.get()?)
     }
-}
-
-/*-------------------------------------------------------------------------------------------------
-  CLI
--------------------------------------------------------------------------------------------------*/
 
-#[derive(clap::Subcommand)]
-#[command(long_about = "Day 10: Hoof It")]
-pub enum Args {
-    Part1 { input: PathBuf },
-    Part2 { input: PathBuf },
-}
-
-pub fn main(args: Args) -> Option<String> {
-    match args {
-        Args::Part1 { input } => part1(&get_input(&input)),
-        Args::Part2 { input } => part2(&get_input(&input)),
+    fn part2(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part2(input.get()?)
     }
 }
 
@@ -241,37 +143,25 @@ pub fn main(args: Args) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::get_answer;
-
-    #[test]
-    fn test_example_solution_part1() {
-        assert_eq!(
-            part1(&get_input("../data/day10/example.txt")),
-            get_answer("../data/day10/example-part1-answer.txt")
-        );
-    }
 
     #[test]
-    fn test_example_solution_part2() {
+    fn test_parse_input_errors() {
+        let error = Input::parse("0123\n12x4\n").err().unwrap();
         assert_eq!(
-            part2(&get_input("../data/day10/example.txt")),
-            get_answer("../data/day10/example-part2-answer.txt")
+            error.to_string(),
+            "Day 10, line 2, column 3: Expected a digit (\"x\")"
         );
     }
 
     #[test]
-    fn test_part1_solution() {
-        assert_eq!(
-            part1(&get_input("../data/day10/input.txt")),
-            get_answer("../data/day10/input-part1-answer.txt")
-        );
-    }
-
-    #[test]
-    fn test_part2_solution() {
-        assert_eq!(
-            part2(&get_input("../data/day10/input.txt")),
-            get_answer("../data/day10/input-part2-answer.txt")
-        );
+    fn test_map_without_trailheads() {
+        let input = Input::parse(
+            "1234
+5678
+",
+        )
+        .unwrap();
+        assert_eq!(part1(&input).unwrap(), 0.into());
+        assert_eq!(part2(&input).unwrap(), 0.into());
     }
 }
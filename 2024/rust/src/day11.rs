@@ -1,41 +1,49 @@
-use crate::get_input;
+use crate::{Answer, Day, InputParser, Parameters, ParsedInput, Solution};
+use anyhow::Result;
 use std::collections::HashMap;
-use std::path::PathBuf;
 
 /*-------------------------------------------------------------------------------------------------
   Day 11: Plutonian Pebbles
 -------------------------------------------------------------------------------------------------*/
 
-pub fn part1(input: &str) -> Option<String> {
-    let stones = parse_input(input);
-    let stone_count = blinks(&stones, 25);
+pub fn part1(input: &Input) -> Result<Answer> {
+    let stone_count = blinks(&input.stones, 25);
 
-    Some(stone_count.to_string())
+    Ok(stone_count.into())
 }
 
-pub fn part2(input: &str) -> Option<String> {
-    let stones = parse_input(input);
-    let stone_count = blinks(&stones, 75);
+pub fn part2(input: &Input) -> Result<Answer> {
+    let stone_count = blinks(&input.stones, 75);
 
-    Some(stone_count.to_string())
+    Ok(stone_count.into())
 }
 
 /*--------------------------------------------------------------------------------------
   Core
 --------------------------------------------------------------------------------------*/
 
+const DAY: Day = 11;
+
 type Stone = u64;
 type StoneCount = usize;
 type BlinkCount = u8;
 
-fn parse_input(input: &str) -> Vec<Stone> {
-    input
-        .lines()
-        .next()
-        .unwrap()
-        .split(' ')
-        .map(|s| s.parse().unwrap())
-        .collect()
+pub struct Input {
+    stones: Vec<Stone>,
+}
+
+impl Input {
+    pub fn parse(input: &str) -> Result<Self> {
+        let parser = InputParser::new(DAY, input);
+
+        let stones = parser
+            .first_line()?
+            .split(' ')
+            .map(|s| parser.parse(s))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { stones })
+    }
 }
 
 fn blinks(stones: &[Stone], count: BlinkCount) -> StoneCount {
@@ -50,7 +58,7 @@ fn transform(stone: &Stone) -> Vec<Stone> {
     let stone_text = stone.to_string();
     if stone == &0 {
         vec![1]
-    } else if stone_text.len() % 2 == 0 {
+    } else if stone_text.len().is_multiple_of(2) {
         let (left, right) = stone_text.split_at(stone_text.len() / 2);
         vec![left.parse().unwrap(), right.parse().unwrap()]
     } else {
@@ -109,17 +117,27 @@ impl Cache {
   CLI
 -------------------------------------------------------------------------------------------------*/
 
-#[derive(clap::Subcommand)]
-#[command(long_about = "Day 11: Plutonian Pebbles")]
-pub enum Args {
-    Part1 { input: PathBuf },
-    Part2 { input: PathBuf },
-}
+pub struct Day11;
+
+impl Solution for Day11 {
+    fn day(&self) -> Day {
+        DAY
+    }
+
+    fn title(&self) -> &'static str {
+        "Plutonian Pebbles"
+    }
 
-pub fn main(args: Args) -> Option<String> {
-    match args {
-        Args::Part1 { input } => part1(&get_input(&input)),
-        Args::Part2 { input } => part2(&get_input(&input)),
+    fn parse(&self, input: &str) -> Result<ParsedInput> {
+        Ok(ParsedInput::new(Input::parse(input)?))
+    }
+
+    fn part1(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part1(input.get()?)
+    }
+
+    fn part2(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part2(input.get()?)
     }
 }
 
@@ -130,29 +148,19 @@ pub fn main(args: Args) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::get_answer;
 
     #[test]
-    fn test_example_solution_part1() {
+    fn test_parse_input_errors() {
+        let error = Input::parse("125 x\n").err().unwrap();
         assert_eq!(
-            part1(&get_input("../data/day11/example.txt")),
-            get_answer("../data/day11/example-part1-answer.txt")
+            error.to_string(),
+            "Day 11, line 1, column 5: invalid digit found in string (\"x\")"
         );
-    }
 
-    #[test]
-    fn test_part1_solution() {
-        assert_eq!(
-            part1(&get_input("../data/day11/input.txt")),
-            get_answer("../data/day11/input-part1-answer.txt")
-        );
-    }
-
-    #[test]
-    fn test_part2_solution() {
+        let error = Input::parse("").err().unwrap();
         assert_eq!(
-            part2(&get_input("../data/day11/input.txt")),
-            get_answer("../data/day11/input-part2-answer.txt")
+            error.to_string(),
+            "Day 11, line 1, column 1: Input is empty"
         );
     }
 }
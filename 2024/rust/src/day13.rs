@@ -1,7 +1,7 @@
-use crate::get_input;
+use crate::{Answer, Day, InputParser, Parameters, ParsedInput, Solution};
+use anyhow::Result;
 use nalgebra::{matrix, vector};
 use regex::Regex;
-use std::path::PathBuf;
 
 /*-------------------------------------------------------------------------------------------------
   Day 13: Claw Contraption
@@ -9,22 +9,20 @@ use std::path::PathBuf;
 
 const F64_TOLERANCE: f64 = 1e-4;
 
-pub fn part1(input: &str) -> Option<String> {
-    let claw_machines = parse_input(input);
-
-    let minimum_token_count = claw_machines
+pub fn part1(input: &Input) -> Result<Answer> {
+    let minimum_token_count = input
+        .claw_machines
         .iter()
         .filter_map(|claw_machine| claw_machine.solve())
         .map(|(a, b)| 3 * a + b)
         .sum::<u64>();
 
-    Some(minimum_token_count.to_string())
+    Ok(minimum_token_count.into())
 }
 
-pub fn part2(input: &str) -> Option<String> {
-    let claw_machines = parse_input(input);
-
-    let updated_measurements = claw_machines
+pub fn part2(input: &Input) -> Result<Answer> {
+    let updated_measurements = input
+        .claw_machines
         .iter()
         .map(|claw_machine| ClawMachine {
             x: claw_machine.x + 10000000000000,
@@ -39,34 +37,49 @@ pub fn part2(input: &str) -> Option<String> {
         .map(|(a, b)| 3 * a + b)
         .sum::<u64>();
 
-    Some(minimum_token_count.to_string())
+    Ok(minimum_token_count.into())
 }
 
 /*--------------------------------------------------------------------------------------
   Core
 --------------------------------------------------------------------------------------*/
 
-fn parse_input(input: &str) -> Vec<ClawMachine> {
-    let claw_machine_regex = Regex::new(
-        r#"(?x)
-        Button\sA:\sX\+(?P<ax>\d+),\sY\+(?P<ay>\d+)\n
-        Button\sB:\sX\+(?P<bx>\d+),\sY\+(?P<by>\d+)\n
-        Prize:\sX=(?P<x>\d+),\sY=(?P<y>\d+)
-        "#,
-    )
-    .unwrap();
-
-    claw_machine_regex
-        .captures_iter(input)
-        .map(|cap| ClawMachine {
-            ax: cap["ax"].parse().unwrap(),
-            bx: cap["bx"].parse().unwrap(),
-            x: cap["x"].parse().unwrap(),
-            ay: cap["ay"].parse().unwrap(),
-            by: cap["by"].parse().unwrap(),
-            y: cap["y"].parse().unwrap(),
-        })
-        .collect()
+const DAY: Day = 13;
+
+pub struct Input {
+    claw_machines: Vec<ClawMachine>,
+}
+
+impl Input {
+    pub fn parse(input: &str) -> Result<Self> {
+        let parser = InputParser::new(DAY, input);
+
+        let claw_machine_regex = Regex::new(
+            r#"(?x)
+            Button\sA:\sX\+(?P<ax>\d+),\sY\+(?P<ay>\d+)\n
+            Button\sB:\sX\+(?P<bx>\d+),\sY\+(?P<by>\d+)\n
+            Prize:\sX=(?P<x>\d+),\sY=(?P<y>\d+)
+            "#,
+        )
+        .unwrap();
+
+        let claw_machines = claw_machine_regex
+            .captures_iter(input)
+            .map(|cap| {
+                let field = |name| parser.parse(cap.name(name).unwrap().as_str());
+                Ok(ClawMachine {
+                    ax: field("ax")?,
+                    bx: field("bx")?,
+                    x: field("x")?,
+                    ay: field("ay")?,
+                    by: field("by")?,
+                    y: field("y")?,
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { claw_machines })
+    }
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -101,17 +114,27 @@ impl ClawMachine {
   CLI
 -------------------------------------------------------------------------------------------------*/
 
-#[derive(clap::Subcommand)]
-#[command(long_about = "Day 13: Claw Contraption")]
-pub enum Args {
-    Part1 { input: PathBuf },
-    Part2 { input: PathBuf },
-}
+pub struct Day13;
+
+impl Solution for Day13 {
+    fn day(&self) -> Day {
+        DAY
+    }
+
+    fn title(&self) -> &'static str {
+        "Claw Contraption"
+    }
+
+    fn parse(&self, input: &str) -> Result<ParsedInput> {
+        Ok(ParsedInput::new(Input::parse(input)?))
+    }
 
-pub fn main(args: Args) -> Option<String> {
-    match args {
-        Args::Part1 { input } => part1(&get_input(&input)),
-        Args::Part2 { input } => part2(&get_input(&input)),
+    fn part1(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part1(input.get()?)
+    }
+
+    fn part2(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part2(input.get()?)
     }
 }
 
@@ -122,29 +145,10 @@ pub fn main(args: Args) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::get_answer;
-
-    #[test]
-    fn test_example_part1() {
-        assert_eq!(
-            part1(&get_input("../data/day13/example.txt")),
-            get_answer("../data/day13/example-part1-answer.txt")
-        );
-    }
-
-    #[test]
-    fn test_part1_solution() {
-        assert_eq!(
-            part1(&get_input("../data/day13/input.txt")),
-            get_answer("../data/day13/input-part1-answer.txt")
-        );
-    }
 
     #[test]
-    fn test_part2_solution() {
-        assert_eq!(
-            part2(&get_input("../data/day13/input.txt")),
-            get_answer("../data/day13/input-part2-answer.txt")
-        );
+    fn test_parse_input_errors() {
+        let error = Input::parse("Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=99999999999999999999999, Y=5400\n").err().unwrap();
+        assert_eq!(error.to_string(), "Day 13, line 3, column 10: number too large to fit in target type (\"99999999999999999999999\")");
     }
 }
@@ -1,15 +1,15 @@
-use crate::get_input;
+use crate::{Answer, Day, InputParser, Parameter, Parameters, ParsedInput, Part, Solution};
+use anyhow::Result;
 use regex::Regex;
 use std::collections::HashMap;
-use std::path::PathBuf;
 
 /*-------------------------------------------------------------------------------------------------
   Day 14: Restroom Redoubt
 -------------------------------------------------------------------------------------------------*/
 
-pub fn part1(input: &str, width: isize, height: isize) -> Option<String> {
+pub fn part1(input: &Input, width: isize, height: isize) -> Result<Answer> {
     let lobby = Lobby::new(width, height);
-    let mut robots = parse_input(input);
+    let mut robots = input.robots.clone();
 
     for _ in 0..100 {
         robots.iter_mut().for_each(|robot| robot.r#move(&lobby));
@@ -17,12 +17,12 @@ pub fn part1(input: &str, width: isize, height: isize) -> Option<String> {
 
     let safety_factor = calculate_safety_factor(&robots, &lobby);
 
-    Some(safety_factor.to_string())
+    Ok(safety_factor.into())
 }
 
-pub fn part2(input: &str, visualize: bool) -> Option<String> {
+pub fn part2(input: &Input, visualize: bool) -> Result<Answer> {
     let lobby = Lobby::new(101, 103);
-    let mut robots = parse_input(input);
+    let mut robots = input.robots.clone();
 
     let mut first_christmas_tree: usize = 0;
 
@@ -39,29 +39,41 @@ pub fn part2(input: &str, visualize: bool) -> Option<String> {
         lobby.print(&robots);
     }
 
-    Some(first_christmas_tree.to_string())
+    Ok(first_christmas_tree.into())
 }
 
 /*--------------------------------------------------------------------------------------
   Core
 --------------------------------------------------------------------------------------*/
 
+const DAY: Day = 14;
+
 type Position = (isize, isize);
 type Velocity = (isize, isize);
 
-fn parse_input(input: &str) -> Vec<Robot> {
-    let robot_regex =
-        Regex::new(r#"p=(?P<px>\d+),(?P<py>\d+)\sv=(?P<vx>-?\d+),(?P<vy>-?\d+)"#).unwrap();
-
-    robot_regex
-        .captures_iter(input)
-        .map(|cap| {
-            Robot::new(
-                (cap["px"].parse().unwrap(), cap["py"].parse().unwrap()),
-                (cap["vx"].parse().unwrap(), cap["vy"].parse().unwrap()),
-            )
-        })
-        .collect()
+pub struct Input {
+    robots: Vec<Robot>,
+}
+
+impl Input {
+    pub fn parse(input: &str) -> Result<Self> {
+        let parser = InputParser::new(DAY, input);
+        let robot_regex =
+            Regex::new(r#"p=(?P<px>\d+),(?P<py>\d+)\sv=(?P<vx>-?\d+),(?P<vy>-?\d+)"#).unwrap();
+
+        let robots = robot_regex
+            .captures_iter(input)
+            .map(|cap| {
+                let field = |name| parser.parse(cap.name(name).unwrap().as_str());
+                Ok(Robot::new(
+                    (field("px")?, field("py")?),
+                    (field("vx")?, field("vy")?),
+                ))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { robots })
+    }
 }
 
 fn calculate_safety_factor(robots: &[Robot], lobby: &Lobby) -> usize {
@@ -81,7 +93,7 @@ fn calculate_safety_factor(robots: &[Robot], lobby: &Lobby) -> usize {
   Robot
 -----------------------------------------------------------------------------*/
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Robot {
     position: Position,
     velocity: Velocity,
@@ -195,34 +207,47 @@ enum Quadrant {
   CLI
 -------------------------------------------------------------------------------------------------*/
 
-#[derive(clap::Subcommand)]
-#[command(long_about = "Day 14: Restroom Redoubt")]
-pub enum Args {
-    Part1 {
-        input: PathBuf,
+const PARAMETERS: &[Parameter] = &[
+    Parameter::value("width", "Width of the lobby", "101")
+        .short('w')
+        .only(Part::Part1),
+    Parameter::value("height", "Height of the lobby", "103")
+        .short('h')
+        .only(Part::Part1),
+    Parameter::flag("visualize", "Print the robots' Christmas tree")
+        .short('v')
+        .only(Part::Part2),
+];
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    fn day(&self) -> Day {
+        DAY
+    }
 
-        #[clap(short, long, default_value = "101")]
-        width: isize,
+    fn title(&self) -> &'static str {
+        "Restroom Redoubt"
+    }
 
-        #[clap(short, long, default_value = "103")]
-        height: isize,
-    },
-    Part2 {
-        input: PathBuf,
+    fn parameters(&self) -> &'static [Parameter] {
+        PARAMETERS
+    }
 
-        #[clap(short, long, default_value = "false")]
-        visualize: bool,
-    },
-}
+    fn parse(&self, input: &str) -> Result<ParsedInput> {
+        Ok(ParsedInput::new(Input::parse(input)?))
+    }
 
-pub fn main(args: Args) -> Option<String> {
-    match args {
-        Args::Part1 {
-            input,
-            width,
-            height,
-        } => part1(&get_input(&input), width, height),
-        Args::Part2 { input, visualize } => part2(&get_input(&input), visualize),
+    fn part1(&self, input: &ParsedInput, parameters: &Parameters) -> Result<Answer> {
+        part1(
+            input.get()?,
+            parameters.get("width")?,
+            parameters.get("height")?,
+        )
+    }
+
+    fn part2(&self, input: &ParsedInput, parameters: &Parameters) -> Result<Answer> {
+        part2(input.get()?, parameters.get("visualize")?)
     }
 }
 
@@ -233,29 +258,12 @@ pub fn main(args: Args) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::get_answer;
-
-    #[test]
-    fn test_example_part1() {
-        assert_eq!(
-            part1(&get_input("../data/day14/example0.txt"), 11, 7),
-            get_answer("../data/day14/example0-part1-answer.txt")
-        );
-    }
-
-    #[test]
-    fn test_part1_solution() {
-        assert_eq!(
-            part1(&get_input("../data/day14/input.txt"), 101, 103),
-            get_answer("../data/day14/input-part1-answer.txt")
-        );
-    }
 
     #[test]
-    fn test_part2_solution() {
-        assert_eq!(
-            part2(&get_input("../data/day14/input.txt"), false),
-            get_answer("../data/day14/input-part2-answer.txt")
-        );
+    fn test_parse_input_errors() {
+        let error = Input::parse("p=0,4 v=3,-3\np=99999999999999999999,3 v=-1,-3\n")
+            .err()
+            .unwrap();
+        assert_eq!(error.to_string(), "Day 14, line 2, column 3: number too large to fit in target type (\"99999999999999999999\")");
     }
 }
@@ -1,12 +1,15 @@
-use crate::{get_input, AnyDirection, GridDirection, GridIndex, GridMap};
-use std::path::PathBuf;
+use crate::{
+    Answer, AnyDirection, Day, GridDirection, GridIndex, GridMap, InputParser, Parameters,
+    ParsedInput, Solution,
+};
+use anyhow::Result;
 
 /*-------------------------------------------------------------------------------------------------
   Day 15: Warehouse Woes
 -------------------------------------------------------------------------------------------------*/
 
-pub fn part1(input: &str) -> Option<String> {
-    let (mut warehouse, directions) = parse_input(input);
+pub fn part1(input: &Input) -> Result<Answer> {
+    let mut warehouse = input.warehouse.clone();
 
     let robot_starting_position = warehouse
         .find(|item| matches!(item, WarehouseItem::Robot))
@@ -14,18 +17,17 @@ pub fn part1(input: &str) -> Option<String> {
 
     let mut robot = Robot::new(robot_starting_position);
 
-    for direction in directions {
+    for &direction in &input.directions {
         robot.attempt_move(&mut warehouse, direction);
     }
 
     let gps_coordinate_sum = calculate_gps_coordinates_sum(&warehouse);
 
-    Some(gps_coordinate_sum.to_string())
+    Ok(gps_coordinate_sum.into())
 }
 
-pub fn part2(input: &str) -> Option<String> {
-    let (warehouse, directions) = parse_input(input);
-    let mut warehouse = modify_warehouse(&warehouse);
+pub fn part2(input: &Input) -> Result<Answer> {
+    let mut warehouse = modify_warehouse(&input.warehouse);
     log::debug!("Starting Warehouse:\n{}", warehouse);
 
     let robot_starting_position = warehouse
@@ -34,40 +36,80 @@ pub fn part2(input: &str) -> Option<String> {
 
     let mut robot = Robot::new(robot_starting_position);
 
-    for direction in directions {
+    for &direction in &input.directions {
         robot.attempt_move(&mut warehouse, direction);
         log::debug!("Direction: {}\n{}", direction, warehouse);
     }
 
     let gps_coordinate_sum = calculate_gps_coordinates_sum(&warehouse);
 
-    Some(gps_coordinate_sum.to_string())
+    Ok(gps_coordinate_sum.into())
 }
 
 /*--------------------------------------------------------------------------------------
   Core
 --------------------------------------------------------------------------------------*/
 
+const DAY: Day = 15;
+
 type Index = i8;
 type Position = GridIndex<Index>;
 type Direction = GridDirection;
-type Answer = u32;
-
-fn parse_input(input: &str) -> (GridMap<Index, WarehouseItem>, Vec<Direction>) {
-    let blank_line_index = input.find("\n\n").unwrap() + 1;
-    let map_str = &input[..blank_line_index];
-    let directions_str = &input[blank_line_index + 1..];
+type GpsCoordinate = u32;
 
-    let warehouse: GridMap<Index, WarehouseItem> =
-        GridMap::from_char_map(map_str, |c| WarehouseItem::try_from(c).unwrap());
+pub struct Input {
+    warehouse: GridMap<Index, WarehouseItem>,
+    directions: Vec<Direction>,
+}
 
-    let directions = directions_str
-        .lines()
-        .flat_map(|line| line.chars())
-        .map(|c| Direction::try_from(c).unwrap())
-        .collect();
+impl Input {
+    pub fn parse(input: &str) -> Result<Self> {
+        let parser = InputParser::new(DAY, input);
+
+        let blank_line_index = input
+            .find("\n\n")
+            .ok_or_else(|| parser.error_at_end("Missing blank line after the warehouse map"))?
+            + 1;
+        let map_str = &input[..blank_line_index];
+        let directions_str = &input[blank_line_index + 1..];
+
+        parser.rectangular(map_str)?;
+
+        let rows = map_str
+            .lines()
+            .map(|line| {
+                line.char_indices()
+                    .map(|(index, c)| {
+                        WarehouseItem::try_from(c)
+                            .map_err(|_| parser.char_error(line, index, "Invalid warehouse item"))
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let warehouse: GridMap<Index, WarehouseItem> =
+            rows.into_iter().map(|row| row.into_iter()).collect();
+
+        if warehouse
+            .find(|item| matches!(item, WarehouseItem::Robot))
+            .is_none()
+        {
+            return Err(parser.error(map_str, "Missing robot '@'").into());
+        }
 
-    (warehouse, directions)
+        let directions = directions_str
+            .lines()
+            .flat_map(|line| line.char_indices().map(move |(index, c)| (line, index, c)))
+            .map(|(line, index, c)| {
+                Direction::try_from(c).map_err(|_| parser.char_error(line, index, "Invalid move"))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            warehouse,
+            directions,
+        })
+    }
 }
 
 fn modify_warehouse(warehouse: &GridMap<Index, WarehouseItem>) -> GridMap<Index, WarehouseItem> {
@@ -86,13 +128,13 @@ fn modify_warehouse(warehouse: &GridMap<Index, WarehouseItem>) -> GridMap<Index,
         .collect()
 }
 
-fn calculate_gps_coordinates_sum(warehouse: &GridMap<Index, WarehouseItem>) -> Answer {
+fn calculate_gps_coordinates_sum(warehouse: &GridMap<Index, WarehouseItem>) -> GpsCoordinate {
     warehouse
         .enumerate()
         .filter(|(_, item)| matches!(**item, WarehouseItem::Box | WarehouseItem::BigBoxLeft))
         .map(|(index, _)| {
-            let row: Answer = index.row.try_into().unwrap();
-            let column: Answer = index.column.try_into().unwrap();
+            let row: GpsCoordinate = index.row.try_into().unwrap();
+            let column: GpsCoordinate = index.column.try_into().unwrap();
             row * 100 + column
         })
         .sum()
@@ -317,17 +359,27 @@ fn map_move(warehouse: &mut GridMap<Index, WarehouseItem>, from: Position, to: P
   CLI
 -------------------------------------------------------------------------------------------------*/
 
-#[derive(clap::Subcommand)]
-#[command(long_about = "Day 15: Warehouse Woes")]
-pub enum Args {
-    Part1 { input: PathBuf },
-    Part2 { input: PathBuf },
-}
+pub struct Day15;
+
+impl Solution for Day15 {
+    fn day(&self) -> Day {
+        DAY
+    }
+
+    fn title(&self) -> &'static str {
+        "Warehouse Woes"
+    }
 
-pub fn main(args: Args) -> Option<String> {
-    match args {
-        Args::Part1 { input } => part1(&get_input(&input)),
-        Args::Part2 { input } => part2(&get_input(&input)),
+    fn parse(&self, input: &str) -> Result<ParsedInput> {
+        Ok(ParsedInput::new(Input::parse(input)?))
+    }
+
+    fn part1(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part1(input.get()?)
+    }
+
+    fn part2(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part2(input.get()?)
     }
 }
 
@@ -338,37 +390,31 @@ pub fn main(args: Args) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::get_answer;
 
     #[test]
-    fn test_example_part1() {
+    fn test_parse_input_errors() {
+        let error = Input::parse("#####\n#.@.#\n#####\n").err().unwrap();
         assert_eq!(
-            part1(&get_input("../data/day15/example0.txt")),
-            get_answer("../data/day15/example0-part1-answer.txt")
+            error.to_string(),
+            "Day 15, line 4, column 1: Missing blank line after the warehouse map"
         );
-    }
 
-    #[test]
-    fn test_part1_solution() {
+        let error = Input::parse("#####\n#.@x#\n#####\n\n<^\n").err().unwrap();
         assert_eq!(
-            part1(&get_input("../data/day15/input.txt")),
-            get_answer("../data/day15/input-part1-answer.txt")
+            error.to_string(),
+            "Day 15, line 2, column 4: Invalid warehouse item (\"x\")"
         );
-    }
 
-    #[test]
-    fn test_example_part2() {
+        let error = Input::parse("#####\n#...#\n#####\n\n<^\n").err().unwrap();
         assert_eq!(
-            part2(&get_input("../data/day15/example0.txt")),
-            get_answer("../data/day15/example0-part2-answer.txt")
+            error.to_string(),
+            "Day 15, line 1, column 1: Missing robot '@' (\"#####\")"
         );
-    }
 
-    #[test]
-    fn test_part2_solution() {
+        let error = Input::parse("#####\n#.@.#\n#####\n\n<x\n").err().unwrap();
         assert_eq!(
-            part2(&get_input("../data/day15/input.txt")),
-            get_answer("../data/day15/input-part2-answer.txt")
+            error.to_string(),
+            "Day 15, line 5, column 2: Invalid move (\"x\")"
         );
     }
 }
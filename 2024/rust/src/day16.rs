@@ -1,186 +1,138 @@
-use crate::{get_input, GridDirection, GridIndex, GridMap};
-use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashMap, HashSet};
-use std::iter::once;
-use std::path::PathBuf;
+use crate::{
+    dijkstra, Answer, Day, GridDirection, GridIndex, GridMap, InputParser, Parameters, ParsedInput,
+    Predecessors, Solution, SolutionError,
+};
+use anyhow::Result;
+use std::collections::HashSet;
 
 /*-------------------------------------------------------------------------------------------------
   Day 16: Reindeer Maze
 -------------------------------------------------------------------------------------------------*/
 
-pub fn part1(input: &str) -> Option<String> {
-    let map = parse_input(input);
-    let (best_score, _) = race(&map);
+pub fn part1(input: &Input) -> Result<Answer> {
+    let (best_score, _) = race(&input.map).ok_or(SolutionError::NoSolution)?;
 
-    Some(best_score.to_string())
+    Ok(best_score.into())
 }
 
-pub fn part2(input: &str) -> Option<String> {
-    let map = parse_input(input);
-    let (_, best_paths_tile_count) = race(&map);
+pub fn part2(input: &Input) -> Result<Answer> {
+    let (_, best_paths_tile_count) = race(&input.map).ok_or(SolutionError::NoSolution)?;
 
-    Some(best_paths_tile_count.to_string())
+    Ok(best_paths_tile_count.into())
 }
 
 /*--------------------------------------------------------------------------------------
   Core
 --------------------------------------------------------------------------------------*/
 
+const DAY: Day = 16;
+
 type Index = i16;
 type TileCount = i16;
 type Score = i32;
 type PositionAndDirection = (GridIndex<Index>, GridDirection);
 
-fn parse_input(input: &str) -> GridMap<Index, char> {
-    input.into()
+pub struct Input {
+    map: GridMap<Index, char>,
 }
 
-// Use Dijkstra's algorithm to find the shortest paths from the start to the goal
-fn race(map: &GridMap<Index, char>) -> (Score, TileCount) {
-    let start = map.find(|&c| c == 'S').unwrap();
-    let goal = map.find(|&c| c == 'E').unwrap();
-
-    let mut dist: HashMap<(GridIndex<Index>, GridDirection), Score> = HashMap::new();
-    let mut prev: HashMap<PositionAndDirection, HashSet<PositionAndDirection>> = HashMap::new();
-    let mut heap: BinaryHeap<State> = BinaryHeap::new();
-
-    let mut best_score: Score = Score::MAX;
-
-    let initial_state = State {
-        position: start,
-        direction: GridDirection::Right,
-        score: 0,
-    };
-    let initial_key = (initial_state.position, initial_state.direction);
-    dist.insert(initial_key, initial_state.score);
-    prev.insert(initial_key, HashSet::new());
-
-    heap.push(initial_state);
-    while let Some(current_state) = heap.pop() {
-        let current_key = (current_state.position, current_state.direction);
-        let current_tile_score = *dist.get(&current_key).unwrap_or(&Score::MAX);
-
-        if current_state.position == goal && current_state.score < best_score {
-            best_score = current_state.score;
-            continue;
-        }
-
-        if current_state.score > current_tile_score || current_state.score >= best_score {
-            continue;
-        }
-
-        for next_state in current_state.next_states(map).iter().filter_map(|s| *s) {
-            let next_key = (next_state.position, next_state.direction);
-            let next_tile_score = dist.get(&next_key).unwrap_or(&Score::MAX);
-
-            match next_state.score.cmp(next_tile_score) {
-                Ordering::Less => {
-                    dist.insert(next_key, next_state.score);
-                    prev.insert(next_key, once(current_key).collect());
-                    heap.push(next_state);
-                }
-                Ordering::Equal => {
-                    prev.entry(next_key).or_default().insert(current_key);
-                    heap.push(next_state);
-                }
-                _ => {}
+impl Input {
+    pub fn parse(input: &str) -> Result<Self> {
+        let parser = InputParser::new(DAY, input);
+        parser.rectangular(input)?;
+
+        let map: GridMap<Index, char> = input.into();
+        for (tile, name) in [('S', "start"), ('E', "end")] {
+            if map.find(|&c| c == tile).is_none() {
+                return Err(parser
+                    .error_at_end(format!("Missing {name} tile {tile:?}"))
+                    .into());
             }
         }
-    }
 
-    let mut tiles: HashSet<GridIndex<Index>> = HashSet::new();
-    let mut stack = vec![
-        (goal, GridDirection::Up),
-        (goal, GridDirection::Down),
-        (goal, GridDirection::Right),
-        (goal, GridDirection::Left),
-    ];
-    while let Some(current) = stack.pop() {
-        if let Some(prevs) = prev.get(&current) {
-            tiles.insert(current.0);
-            for prev in prevs {
-                stack.push(*prev);
-            }
-        }
+        Ok(Self { map })
     }
+}
 
-    (best_score, tiles.len() as TileCount)
+// Use Dijkstra's algorithm to find the best score and every tile on the best paths, if the end can
+// be reached
+fn race(map: &GridMap<Index, char>) -> Option<(Score, TileCount)> {
+    let start = map.find(|&c| c == 'S').unwrap();
+    let goal = map.find(|&c| c == 'E').unwrap();
+
+    let search = dijkstra(
+        (start, GridDirection::Right),
+        |&state| next_moves(map, state),
+        |&(position, _)| position == goal,
+        Predecessors::All,
+    );
+
+    let best_score = search.goal_distance()?;
+    let tiles: HashSet<GridIndex<Index>> = search
+        .shortest_path_states()
+        .into_iter()
+        .map(|(position, _)| position)
+        .collect();
+
+    Some((best_score, tiles.len() as TileCount))
 }
 
 /*-----------------------------------------------------------------------------
-  State
+  Moves
 -----------------------------------------------------------------------------*/
 
-#[derive(Copy, Clone, Eq, PartialEq)]
-struct State {
+fn next_moves(
+    map: &GridMap<Index, char>,
+    (position, direction): PositionAndDirection,
+) -> impl Iterator<Item = (PositionAndDirection, Score)> + '_ {
+    let forward = direction_is_clear(map, position, direction).then(|| {
+        let next_position = map.project_direction(position, direction).unwrap();
+        ((next_position, direction), 1)
+    });
+
+    let turns = [direction.turn_right(), direction.turn_left()]
+        .into_iter()
+        .filter(move |&turned| direction_is_clear(map, position, turned))
+        .map(move |turned| ((position, turned), 1000));
+
+    forward.into_iter().chain(turns)
+}
+
+fn direction_is_clear(
+    map: &GridMap<Index, char>,
     position: GridIndex<Index>,
     direction: GridDirection,
-    score: Score,
+) -> bool {
+    // Off-grid tiles are blocked, like walls, so maps without a border wall can't escape the grid
+    map.get_direction(position, direction)
+        .is_some_and(|&tile| tile != '#')
 }
 
-impl State {
-    fn next_states(&self, map: &GridMap<Index, char>) -> [Option<Self>; 3] {
-        [
-            self.direction_is_clear(map, self.direction)
-                .then_some(State {
-                    position: map
-                        .project_direction(self.position, self.direction)
-                        .unwrap(),
-                    direction: self.direction,
-                    score: self.score + 1,
-                }),
-            self.direction_is_clear(map, self.direction.turn_right())
-                .then_some(State {
-                    position: self.position,
-                    direction: self.direction.turn_right(),
-                    score: self.score + 1000,
-                }),
-            self.direction_is_clear(map, self.direction.turn_left())
-                .then_some(State {
-                    position: self.position,
-                    direction: self.direction.turn_left(),
-                    score: self.score + 1000,
-                }),
-        ]
-    }
+/*-------------------------------------------------------------------------------------------------
+  CLI
+-------------------------------------------------------------------------------------------------*/
 
-    fn direction_is_clear(&self, map: &GridMap<Index, char>, direction: GridDirection) -> bool {
-        let new_position = map.project_direction(self.position, direction).unwrap();
-        map.get(new_position) != Some(&'#')
-    }
-}
+pub struct Day16;
 
-impl Ord for State {
-    fn cmp(&self, other: &Self) -> Ordering {
-        // Reverse ordering to make the heap a min-heap
-        other
-            .score
-            .cmp(&self.score)
-            .then_with(|| self.position.cmp(&other.position))
+impl Solution for Day16 {
+    fn day(&self) -> Day {
+        DAY
     }
-}
 
-impl PartialOrd for State {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+    fn title(&self) -> &'static str {
+        "Reindeer Maze"
     }
-}
 
-/*-------------------------------------------------------------------------------------------------
-  CLI
--------------------------------------------------------------------------------------------------*/
+    fn parse(&self, input: &str) -> Result<ParsedInput> {
+        Ok(ParsedInput::new(Input::parse(input)?))
+    }
 
-#[derive(clap::Subcommand)]
-#[command(long_about = "Day 16: Reindeer Maze")]
-pub enum Args {
-    Part1 { input: PathBuf },
-    Part2 { input: PathBuf },
-}
+    fn part1(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part1(input.get()?)
+    }
 
-pub fn main(args: Args) -> Option<String> {
-    match args {
-        Args::Part1 { input } => part1(&get_input(&input)),
-        Args::Part2 { input } => part2(&get_input(&input)),
+    fn part2(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part2(input.get()?)
     }
 }
 
@@ -191,53 +143,46 @@ pub fn main(args: Args) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::get_answer;
-
-    #[test]
-    fn test_example0_part1() {
-        assert_eq!(
-            part1(&get_input("../data/day16/example0.txt")),
-            get_answer("../data/day16/example0-part1-answer.txt")
-        );
-    }
 
     #[test]
-    fn test_example1_part1() {
+    fn test_parse_input_errors() {
+        let error = Input::parse("#####\n#S.E\n#####\n").err().unwrap();
         assert_eq!(
-            part1(&get_input("../data/day16/example1.txt")),
-            get_answer("../data/day16/example1-part1-answer.txt")
+            error.to_string(),
+            "Day 16, line 2, column 1: Expected 5 columns, found 4 (\"#S.E\")"
         );
-    }
-
-    #[test]
-    fn test_part1_solution() {
-        assert_eq!(
-            part1(&get_input("../data/day16/input.txt")),
-            get_answer("../data/day16/input-part1-answer.txt")
-        );
-    }
 
-    #[test]
-    fn test_example0_part2() {
+        let error = Input::parse("#####\n#S..#\n#####\n").err().unwrap();
         assert_eq!(
-            part2(&get_input("../data/day16/example0.txt")),
-            get_answer("../data/day16/example0-part2-answer.txt")
+            error.to_string(),
+            "Day 16, line 4, column 1: Missing end tile 'E'"
         );
     }
 
     #[test]
-    fn test_example1_part2() {
-        assert_eq!(
-            part2(&get_input("../data/day16/example1.txt")),
-            get_answer("../data/day16/example1-part2-answer.txt")
-        );
+    fn test_map_without_border_wall() {
+        let input = Input::parse(
+            "S.
+.E
+",
+        )
+        .unwrap();
+        assert_eq!(part1(&input).unwrap(), 1002.into());
+        assert_eq!(part2(&input).unwrap(), 3.into());
     }
 
     #[test]
-    fn test_part2_solution() {
-        assert_eq!(
-            part2(&get_input("../data/day16/input.txt")),
-            get_answer("../data/day16/input-part2-answer.txt")
-        );
+    fn test_unreachable_end() {
+        let input = Input::parse(
+            "S#E
+",
+        )
+        .unwrap();
+        for error in [part1(&input).unwrap_err(), part2(&input).unwrap_err()] {
+            assert!(matches!(
+                error.downcast_ref::<SolutionError>(),
+                Some(SolutionError::NoSolution)
+            ));
+        }
     }
 }
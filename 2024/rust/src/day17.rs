@@ -1,34 +1,31 @@
-use crate::get_input;
+use crate::{Answer, Day, InputParser, Parameters, ParsedInput, Solution, SolutionError};
+use anyhow::Result;
 use regex::Regex;
-use std::path::PathBuf;
-
-use itertools::Itertools;
 
 /*-------------------------------------------------------------------------------------------------
   Day 17: Chronospatial Computer
 -------------------------------------------------------------------------------------------------*/
 
-pub fn part1(input: &str) -> Option<String> {
-    let (registers, program) = parse_input(input);
-    let mut computer = Computer::new(registers, &program);
+pub fn part1(input: &Input) -> Result<Answer> {
+    let mut computer = Computer::new(input.registers, &input.program);
     computer.run();
 
-    Some(computer.format_output())
+    Ok(computer.output.iter().collect())
 }
 
-pub fn part2(input: &str) -> Option<String> {
-    let (registers, program) = parse_input(input);
-
-    let new_registers = [0, registers[1], registers[2]];
-    let register_a = register_a_solver(new_registers, &program, 1).unwrap();
+pub fn part2(input: &Input) -> Result<Answer> {
+    let new_registers = [0, input.registers[1], input.registers[2]];
+    let register_a = register_a_solver(new_registers, &input.program, 1);
 
-    Some(register_a.to_string())
+    Ok(register_a.ok_or(SolutionError::NoSolution)?.into())
 }
 
 /*--------------------------------------------------------------------------------------
   Core
 --------------------------------------------------------------------------------------*/
 
+const DAY: Day = 17;
+
 type Instruction = u8;
 type Operand = u8;
 type LiteralOperand = Operand;
@@ -38,32 +35,47 @@ type Pointer = usize;
 type Program = Vec<u8>;
 type Output = Vec<u8>;
 
-fn parse_input(input: &str) -> ([RegisterValue; 3], Program) {
-    let input_regex = Regex::new(
-        r#"(?x)
-        Register\sA:\s(?P<register_a>\d+)\n
-        Register\sB:\s(?P<register_b>\d+)\n
-        Register\sC:\s(?P<register_c>\d+)\n
-        \n
-        Program:\s(?P<program>(?:\d,?)+)
-    "#,
-    )
-    .unwrap();
-
-    let parsed_input = input_regex.captures(input).unwrap();
-
-    let registers = [
-        parsed_input["register_a"].parse().unwrap(),
-        parsed_input["register_b"].parse().unwrap(),
-        parsed_input["register_c"].parse().unwrap(),
-    ];
-
-    let program = parsed_input["program"]
-        .split(',')
-        .map(|opcode| opcode.parse().unwrap())
-        .collect();
-
-    (registers, program)
+pub struct Input {
+    registers: [RegisterValue; 3],
+    program: Program,
+}
+
+impl Input {
+    pub fn parse(input: &str) -> Result<Self> {
+        let parser = InputParser::new(DAY, input);
+
+        let input_regex = Regex::new(
+            r#"(?x)
+            Register\sA:\s(?P<register_a>\d+)\n
+            Register\sB:\s(?P<register_b>\d+)\n
+            Register\sC:\s(?P<register_c>\d+)\n
+            \n
+            Program:\s(?P<program>(?:\d,?)+)
+        "#,
+        )
+        .unwrap();
+
+        let parsed_input = input_regex
+            .captures(input)
+            .ok_or_else(|| parser.error(input, "Expected three registers and a program"))?;
+        let field = |name| parsed_input.name(name).unwrap().as_str();
+
+        let registers = [
+            parser.parse(field("register_a"))?,
+            parser.parse(field("register_b"))?,
+            parser.parse(field("register_c"))?,
+        ];
+
+        let program = field("program")
+            .split(',')
+            .map(|value| match parser.parse(value)? {
+                value @ 0..=7 => Ok(value),
+                _ => Err(parser.error(value, "Expected a 3-bit number")),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { registers, program })
+    }
 }
 
 fn register_a_solver(
@@ -130,13 +142,6 @@ impl<'p> Computer<'p> {
         self.instruction_pointer += 2;
     }
 
-    fn format_output(&self) -> String {
-        self.output
-            .iter()
-            .map(|output| output.to_string())
-            .join(",")
-    }
-
     fn run(&mut self) {
         while self.instruction_pointer < self.program.len() {
             let opcode = self.program[self.instruction_pointer];
@@ -278,17 +283,27 @@ impl<'p> Computer<'p> {
   CLI
 -------------------------------------------------------------------------------------------------*/
 
-#[derive(clap::Subcommand)]
-#[command(long_about = "Day 17: Chronospatial Computer")]
-pub enum Args {
-    Part1 { input: PathBuf },
-    Part2 { input: PathBuf },
-}
+pub struct Day17;
+
+impl Solution for Day17 {
+    fn day(&self) -> Day {
+        DAY
+    }
+
+    fn title(&self) -> &'static str {
+        "Chronospatial Computer"
+    }
+
+    fn parse(&self, input: &str) -> Result<ParsedInput> {
+        Ok(ParsedInput::new(Input::parse(input)?))
+    }
+
+    fn part1(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part1(input.get()?)
+    }
 
-pub fn main(args: Args) -> Option<String> {
-    match args {
-        Args::Part1 { input } => part1(&get_input(&input)),
-        Args::Part2 { input } => part2(&get_input(&input)),
+    fn part2(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part2(input.get()?)
     }
 }
 
@@ -299,38 +314,24 @@ pub fn main(args: Args) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::get_answer;
 
     #[test]
-    fn test_example_part1() {
+    fn test_parse_input_errors() {
+        let error = Input::parse("Register A: 729\nRegister B: 0\n\nProgram: 0,1\n")
+            .err()
+            .unwrap();
         assert_eq!(
-            part1(&get_input("../data/day17/example0.txt")),
-            get_answer("../data/day17/example0-part1-answer.txt")
+            error.to_string(),
+            "Day 17, line 1, column 1: Expected three registers and a program \
+             (\"Register A: 729\")"
         );
-    }
 
-    #[test]
-    #[cfg_attr(not(feature = "slow_tests"), ignore)]
-    fn test_part1_solution() {
-        assert_eq!(
-            part1(&get_input("../data/day17/input.txt")),
-            get_answer("../data/day17/input-part1-answer.txt")
-        );
-    }
-
-    #[test]
-    fn test_example_part2() {
-        assert_eq!(
-            part2(&get_input("../data/day17/example1.txt")),
-            get_answer("../data/day17/example1-part2-answer.txt")
-        );
-    }
-
-    #[test]
-    fn test_part2_solution() {
+        let error = Input::parse("Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,8\n")
+            .err()
+            .unwrap();
         assert_eq!(
-            part2(&get_input("../data/day17/input.txt")),
-            get_answer("../data/day17/input-part2-answer.txt")
+            error.to_string(),
+            "Day 17, line 5, column 12: Expected a 3-bit number (\"8\")"
         );
     }
 }
@@ -1,47 +1,51 @@
-use crate::{get_input, GridDirection, GridIndex, GridMap};
-use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashMap};
-use std::path::PathBuf;
+use crate::{
+    bfs, Answer, Day, GridIndex, GridMap, InputParser, Parameter, Parameters, ParsedInput,
+    Predecessors, Solution, SolutionError,
+};
+use anyhow::Result;
 
 /*-------------------------------------------------------------------------------------------------
   Day 18: RAM Run
 -------------------------------------------------------------------------------------------------*/
 
-pub fn part1(input: &str) -> Option<String> {
-    let corrupted_memory_positions = parse_input(input);
-    let mut map = GridMap::new(70 + 1, 70 + 1, '.');
-    for position in corrupted_memory_positions.iter().take(1024) {
-        map.set(*position, '#').unwrap();
+pub fn part1(input: &Input, size: Index, bytes: usize) -> Result<Answer> {
+    let corrupted_memory_positions = &input.corrupted_memory_positions;
+    let mut map = GridMap::new(size + 1, size + 1, '.');
+    for position in corrupted_memory_positions.iter().take(bytes) {
+        map.set(*position, '#')?;
     }
 
     let number_of_steps_to_exit = escape_route(&map);
 
-    number_of_steps_to_exit.map(|steps| steps.to_string())
+    Ok(number_of_steps_to_exit
+        .ok_or(SolutionError::NoSolution)?
+        .into())
 }
 
-pub fn part2(input: &str) -> Option<String> {
-    let corrupted_memory_positions = parse_input(input);
-    let mut map = GridMap::new(70 + 1, 70 + 1, '.');
+pub fn part2(input: &Input, size: Index, bytes: usize) -> Result<Answer> {
+    let corrupted_memory_positions = &input.corrupted_memory_positions;
+    let mut map = GridMap::new(size + 1, size + 1, '.');
 
-    for position in corrupted_memory_positions.iter().take(1024) {
-        map.set(*position, '#').unwrap();
+    for position in corrupted_memory_positions.iter().take(bytes) {
+        map.set(*position, '#')?;
     }
 
-    let mut death_block: GridIndex<Index> = GridIndex::new(0, 0);
+    let mut death_block: Option<GridIndex<Index>> = None;
 
-    for position in corrupted_memory_positions.iter().skip(1024) {
-        map.set(*position, '#').unwrap();
+    for position in corrupted_memory_positions.iter().skip(bytes) {
+        map.set(*position, '#')?;
         if escape_route(&map).is_none() {
-            death_block = *position;
+            death_block = Some(*position);
             break;
         };
     }
 
+    let death_block = death_block.ok_or(SolutionError::NoSolution)?;
+
     // GridIndex<Index> is (row, column)
-    Some(format!(
-        "{x},{y}",
-        x = death_block.column,
-        y = death_block.row
+    Ok(Answer::Coordinate(
+        death_block.column.into(),
+        death_block.row.into(),
     ))
 }
 
@@ -49,122 +53,97 @@ pub fn part2(input: &str) -> Option<String> {
   Core
 --------------------------------------------------------------------------------------*/
 
+const DAY: Day = 18;
+
 type Index = i8;
-type Steps = u16;
-
-fn parse_input(input: &str) -> Vec<GridIndex<Index>> {
-    input
-        .lines()
-        .map(|line| {
-            let mut split = line.split(',');
-            let x = split.next().unwrap().parse().unwrap();
-            let y = split.next().unwrap().parse().unwrap();
-            (y, x).into() // GridIndex<Index> is (row, column)
+type Steps = usize;
+
+pub struct Input {
+    corrupted_memory_positions: Vec<GridIndex<Index>>,
+}
+
+impl Input {
+    pub fn parse(input: &str) -> Result<Self> {
+        let parser = InputParser::new(DAY, input);
+
+        let corrupted_memory_positions = input
+            .lines()
+            .map(|line| {
+                let mut split = line.split(',');
+                let x = parser.parse(parser.required(split.next(), line)?)?;
+                let y = parser.parse(parser.required(split.next(), line)?)?;
+                Ok((y, x).into()) // GridIndex<Index> is (row, column)
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            corrupted_memory_positions,
         })
-        .collect()
+    }
 }
 
-// Use Dijkstra's algorithm to find the shortest paths from the start to the goal
+// Use a breadth-first search to find the fewest steps from the start to the goal
 fn escape_route(map: &GridMap<Index, char>) -> Option<Steps> {
     let start = (0, 0).into();
     let goal = (map.rows() - 1, map.columns() - 1).into();
 
-    let mut dist: HashMap<GridIndex<Index>, Steps> = HashMap::new();
-    let mut prev: HashMap<GridIndex<Index>, GridIndex<Index>> = HashMap::new();
-    let mut heap: BinaryHeap<State> = BinaryHeap::new();
-
-    let initial = State {
-        position: start,
-        steps: 0,
-    };
-    dist.insert(initial.position, initial.steps);
-    heap.push(initial);
-    while let Some(current) = heap.pop() {
-        if current.position == goal {
-            break;
-        }
-
-        let current_tile_best_steps = *dist.get(&current.position).unwrap_or(&Steps::MAX);
-        if current.steps > current_tile_best_steps {
-            continue;
-        }
-
-        for next in current.next_states(map).iter().filter_map(|s| *s) {
-            let next_tile_best_steps = *dist.get(&next.position).unwrap_or(&Steps::MAX);
-            if next.steps < next_tile_best_steps {
-                dist.insert(next.position, next.steps);
-                prev.insert(next.position, current.position);
-                heap.push(next);
-            }
-        }
-    }
-
-    dist.get(&goal).copied()
+    let search = bfs(
+        start,
+        |&position| {
+            map.neighbors(position)
+                .filter(|(_, &tile)| tile != '#')
+                .map(|(next_position, _)| next_position)
+                .collect::<Vec<_>>()
+        },
+        |&position| position == goal,
+        Predecessors::First,
+    );
+
+    search.goal_distance()
 }
 
-/*-----------------------------------------------------------------------------
-  State
------------------------------------------------------------------------------*/
+/*-------------------------------------------------------------------------------------------------
+  CLI
+-------------------------------------------------------------------------------------------------*/
 
-#[derive(Copy, Clone, Eq, PartialEq)]
-struct State {
-    position: GridIndex<Index>,
-    steps: Steps,
-}
+const PARAMETERS: &[Parameter] = &[
+    Parameter::value("size", "Largest coordinate in the memory space", "70"),
+    Parameter::value("bytes", "Number of bytes that have fallen", "1024"),
+];
 
-impl State {
-    fn next_states(&self, map: &GridMap<Index, char>) -> [Option<Self>; 4] {
-        [
-            GridDirection::Up,
-            GridDirection::Down,
-            GridDirection::Right,
-            GridDirection::Left,
-        ]
-        .map(|direction| {
-            let next_position = map.project_direction(self.position, direction)?;
-            position_is_clear(map, next_position).then_some(State {
-                position: next_position,
-                steps: self.steps + 1,
-            })
-        })
-    }
-}
+pub struct Day18;
 
-fn position_is_clear(map: &GridMap<Index, char>, position: GridIndex<Index>) -> bool {
-    map.get(position) != Some(&'#')
-}
+impl Solution for Day18 {
+    fn day(&self) -> Day {
+        DAY
+    }
 
-impl Ord for State {
-    fn cmp(&self, other: &Self) -> Ordering {
-        // Reverse ordering to make the heap a min-heap
-        other
-            .steps
-            .cmp(&self.steps)
-            .then_with(|| self.position.cmp(&other.position))
+    fn title(&self) -> &'static str {
+        "RAM Run"
     }
-}
 
-impl PartialOrd for State {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+    fn parameters(&self) -> &'static [Parameter] {
+        PARAMETERS
     }
-}
 
-/*-------------------------------------------------------------------------------------------------
-  CLI
--------------------------------------------------------------------------------------------------*/
+    fn parse(&self, input: &str) -> Result<ParsedInput> {
+        Ok(ParsedInput::new(Input::parse(input)?))
+    }
 
-#[derive(clap::Subcommand)]
-#[command(long_about = "Day 18: RAM Run")]
-pub enum Args {
-    Part1 { input: PathBuf },
-    Part2 { input: PathBuf },
-}
+    fn part1(&self, input: &ParsedInput, parameters: &Parameters) -> Result<Answer> {
+        part1(
+            input.get()?,
+            parameters.get("size")?,
+            parameters.get("bytes")?,
+        )
+    }
 
-pub fn main(args: Args) -> Option<String> {
-    match args {
-        Args::Part1 { input } => part1(&get_input(&input)),
-        Args::Part2 { input } => part2(&get_input(&input)),
+    fn part2(&self, input: &ParsedInput, parameters: &Parameters) -> Result<Answer> {
+        part2(
+            input.get()?,
+            parameters.get("size")?,
+            parameters.get("bytes")?,
+        )
     }
 }
 
@@ -172,46 +151,32 @@ pub fn main(args: Args) -> Option<String> {
   Tests
 -------------------------------------------------------------------------------------------------*/
 
-#[cfg(test)]
-fn example_part1(input: &str) -> Option<String> {
-    let corrupted_memory_positions = parse_input(input);
-    let mut map = GridMap::new(6 + 1, 6 + 1, '.');
-    for position in corrupted_memory_positions.iter().take(12) {
-        map.set(*position, '#').unwrap();
-    }
-
-    let number_of_steps_to_exit = escape_route(&map);
-
-    number_of_steps_to_exit.map(|steps| steps.to_string())
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::get_answer;
 
     #[test]
-    fn test_example_part1() {
-        assert_eq!(
-            example_part1(&get_input("../data/day18/example.txt")),
-            get_answer("../data/day18/example-part1-answer.txt")
-        );
+    fn test_part2_without_cut_off() {
+        let input = Input::parse("1,1\n0,2\n").unwrap();
+        let error = part2(&input, 2, 0).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<SolutionError>(),
+            Some(SolutionError::NoSolution)
+        ));
     }
 
     #[test]
-    fn test_part1_solution() {
+    fn test_parse_input_errors() {
+        let error = Input::parse("5,4\n4\n").err().unwrap();
         assert_eq!(
-            part1(&get_input("../data/day18/input.txt")),
-            get_answer("../data/day18/input-part1-answer.txt")
+            error.to_string(),
+            "Day 18, line 2, column 1: Missing value (\"4\")"
         );
-    }
 
-    #[test]
-    #[cfg_attr(not(feature = "slow_tests"), ignore)]
-    fn test_part2_solution() {
+        let error = Input::parse("5,4\nx,2\n").err().unwrap();
         assert_eq!(
-            part2(&get_input("../data/day18/input.txt")),
-            get_answer("../data/day18/input-part2-answer.txt")
+            error.to_string(),
+            "Day 18, line 2, column 1: invalid digit found in string (\"x\")"
         );
     }
 }
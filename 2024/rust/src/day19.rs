@@ -1,58 +1,67 @@
-use crate::get_input;
+use crate::{Answer, Day, InputParser, Parameters, ParsedInput, Solution};
+use anyhow::Result;
 use cached::proc_macro::cached;
 use regex::Regex;
-use std::path::PathBuf;
 
 /*-------------------------------------------------------------------------------------------------
   Day 19: Linen Layout
 -------------------------------------------------------------------------------------------------*/
 
-pub fn part1(input: &str) -> Option<String> {
-    let (patterns, designs) = parse_input(input);
+pub fn part1(input: &Input) -> Result<Answer> {
+    let Input { patterns, designs } = input;
 
-    let pattern_regex = Regex::new(format!("^({})+$", patterns.join("|")).as_str()).unwrap();
+    let pattern_regex = Regex::new(format!("^({})+$", patterns.join("|")).as_str())?;
 
     let possible_designs_count = designs
         .iter()
         .filter(|design| pattern_regex.is_match(design))
         .count();
 
-    Some(possible_designs_count.to_string())
+    Ok(possible_designs_count.into())
 }
 
-pub fn part2(input: &str) -> Option<String> {
-    let (patterns, designs) = parse_input(input);
+pub fn part2(input: &Input) -> Result<Answer> {
+    let Input { patterns, designs } = input;
 
     let all_possible_design_combinations_count = designs
-        .into_iter()
-        .map(|design| count_ways_to_make_design(design, patterns.clone()))
+        .iter()
+        .map(|design| count_ways_to_make_design(design.clone(), patterns.clone()))
         .sum::<DesignCount>();
 
-    Some(all_possible_design_combinations_count.to_string())
+    Ok(all_possible_design_combinations_count.into())
 }
 
 /*--------------------------------------------------------------------------------------
   Core
 --------------------------------------------------------------------------------------*/
 
+const DAY: Day = 19;
+
 type Pattern = String;
 type Patterns = Vec<Pattern>;
 type Design = String;
 type Designs = Vec<Design>;
 type DesignCount = u64;
 
-fn parse_input(input: &str) -> (Patterns, Designs) {
-    let patterns = input
-        .lines()
-        .next()
-        .unwrap()
-        .split(", ")
-        .map(|s| s.to_string())
-        .collect();
+pub struct Input {
+    patterns: Patterns,
+    designs: Designs,
+}
+
+impl Input {
+    pub fn parse(input: &str) -> Result<Self> {
+        let parser = InputParser::new(DAY, input);
 
-    let designs = input.lines().skip(2).map(|s| s.to_string()).collect();
+        let patterns = parser
+            .first_line()?
+            .split(", ")
+            .map(|s| s.to_string())
+            .collect();
 
-    (patterns, designs)
+        let designs = input.lines().skip(2).map(|s| s.to_string()).collect();
+
+        Ok(Self { patterns, designs })
+    }
 }
 
 #[cached]
@@ -79,17 +88,27 @@ fn count_ways_to_make_design(design: String, patterns: Patterns) -> DesignCount
   CLI
 -------------------------------------------------------------------------------------------------*/
 
-#[derive(clap::Subcommand)]
-#[command(long_about = "Day 19: Linen Layout")]
-pub enum Args {
-    Part1 { input: PathBuf },
-    Part2 { input: PathBuf },
-}
+pub struct Day19;
+
+impl Solution for Day19 {
+    fn day(&self) -> Day {
+        DAY
+    }
+
+    fn title(&self) -> &'static str {
+        "Linen Layout"
+    }
+
+    fn parse(&self, input: &str) -> Result<ParsedInput> {
+        Ok(ParsedInput::new(Input::parse(input)?))
+    }
 
-pub fn main(args: Args) -> Option<String> {
-    match args {
-        Args::Part1 { input } => part1(&get_input(&input)),
-        Args::Part2 { input } => part2(&get_input(&input)),
+    fn part1(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part1(input.get()?)
+    }
+
+    fn part2(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part2(input.get()?)
     }
 }
 
@@ -100,30 +119,13 @@ pub fn main(args: Args) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::get_answer;
-
-    #[test]
-    fn test_example_part1() {
-        assert_eq!(
-            part1(&get_input("../data/day19/example.txt")),
-            get_answer("../data/day19/example-part1-answer.txt")
-        );
-    }
-
-    #[test]
-    fn test_part1_solution() {
-        assert_eq!(
-            part1(&get_input("../data/day19/input.txt")),
-            get_answer("../data/day19/input-part1-answer.txt")
-        );
-    }
 
     #[test]
-    #[cfg_attr(not(feature = "slow_tests"), ignore)]
-    fn test_part2_solution() {
+    fn test_parse_input_errors() {
+        let error = Input::parse("").err().unwrap();
         assert_eq!(
-            part2(&get_input("../data/day19/input.txt")),
-            get_answer("../data/day19/input-part2-answer.txt")
+            error.to_string(),
+            "Day 19, line 1, column 1: Input is empty"
         );
     }
 }
@@ -1,40 +1,59 @@
-use crate::get_input;
-use std::path::PathBuf;
+use crate::{Answer, Day, InputParser, Parameters, ParsedInput, Solution};
+use anyhow::Result;
 
 /*-------------------------------------------------------------------------------------------------
   Day 2: Red-Nosed Reports
 -------------------------------------------------------------------------------------------------*/
 
-pub fn part1(input: &str) -> Option<String> {
+pub fn part1(input: &Input) -> Result<Answer> {
     let safe_report_count = input
-        .lines()
-        .map(parse_line)
-        .map(|report| report_status(&report))
+        .reports
+        .iter()
+        .map(|report| report_status(report))
         .filter(|&status| status)
         .count();
 
-    Some(safe_report_count.to_string())
+    Ok(safe_report_count.into())
 }
 
-pub fn part2(input: &str) -> Option<String> {
+pub fn part2(input: &Input) -> Result<Answer> {
     let updated_safe_report_count = input
-        .lines()
-        .map(parse_line)
-        .map(|report| report_status_with_problem_dampener(&report))
+        .reports
+        .iter()
+        .map(|report| report_status_with_problem_dampener(report))
         .filter(|&status| status)
         .count();
 
-    Some(updated_safe_report_count.to_string())
+    Ok(updated_safe_report_count.into())
 }
 
 /*--------------------------------------------------------------------------------------
   Core
 --------------------------------------------------------------------------------------*/
 
+const DAY: Day = 2;
+
 type Level = i8;
 
-fn parse_line(line: &str) -> Vec<Level> {
-    line.split(' ').map(|s| s.parse().unwrap()).collect()
+pub struct Input {
+    reports: Vec<Vec<Level>>,
+}
+
+impl Input {
+    pub fn parse(input: &str) -> Result<Self> {
+        let parser = InputParser::new(DAY, input);
+
+        let reports = input
+            .lines()
+            .map(|line| {
+                line.split(' ')
+                    .map(|s| Ok(parser.parse(s)?))
+                    .collect::<Result<Vec<Level>>>()
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { reports })
+    }
 }
 
 fn report_all_increasing(report: &[Level]) -> bool {
@@ -79,17 +98,27 @@ fn report_status_with_problem_dampener(report: &[Level]) -> bool {
   CLI
 -------------------------------------------------------------------------------------------------*/
 
-#[derive(clap::Subcommand)]
-#[command(long_about = "Day 2: Red-Nosed Reports")]
-pub enum Args {
-    Part1 { input: PathBuf },
-    Part2 { input: PathBuf },
-}
+pub struct Day2;
 
-pub fn main(args: Args) -> Option<String> {
-    match args {
-        Args::Part1 { input } => part1(&get_input(&input)),
-        Args::Part2 { input } => part2(&get_input(&input)),
+impl Solution for Day2 {
+    fn day(&self) -> Day {
+        DAY
+    }
+
+    fn title(&self) -> &'static str {
+        "Red-Nosed Reports"
+    }
+
+    fn parse(&self, input: &str) -> Result<ParsedInput> {
+        Ok(ParsedInput::new(Input::parse(input)?))
+    }
+
+    fn part1(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part1(input.get()?)
+    }
+
+    fn part2(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part2(input.get()?)
     }
 }
 
@@ -100,37 +129,19 @@ pub fn main(args: Args) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::get_answer;
 
     #[test]
-    fn test_example_solution_part1() {
+    fn test_parse_input_errors() {
+        let error = Input::parse("7 6 4\n1 x 3\n").err().unwrap();
         assert_eq!(
-            part1(&get_input("../data/day2/example.txt")),
-            get_answer("../data/day2/example-part1-answer.txt")
+            error.to_string(),
+            "Day 2, line 2, column 3: invalid digit found in string (\"x\")"
         );
-    }
-
-    #[test]
-    fn test_example_solution_part2() {
-        assert_eq!(
-            part2(&get_input("../data/day2/example.txt")),
-            get_answer("../data/day2/example-part2-answer.txt")
-        );
-    }
 
-    #[test]
-    fn test_part1_solution() {
-        assert_eq!(
-            part1(&get_input("../data/day2/input.txt")),
-            get_answer("../data/day2/input-part1-answer.txt")
-        );
-    }
-
-    #[test]
-    fn test_part2_solution() {
+        let error = Input::parse("7 6 4\n\n").err().unwrap();
         assert_eq!(
-            part2(&get_input("../data/day2/input.txt")),
-            get_answer("../data/day2/input-part2-answer.txt")
+            error.to_string(),
+            "Day 2, line 2, column 1: cannot parse integer from empty string"
         );
     }
 }
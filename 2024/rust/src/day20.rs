@@ -1,61 +1,90 @@
-use crate::{get_input, GridDirection, GridIndex, GridMap};
+use crate::{
+    bfs, span, Answer, Day, GridIndex, GridMap, InputParser, Parameters, ParsedInput, Predecessors,
+    Solution, SolutionError,
+};
+use anyhow::Result;
 use cached::proc_macro::cached;
 use itertools::Itertools;
 use std::collections::{BTreeMap, HashMap};
-use std::path::PathBuf;
-use strum::IntoEnumIterator;
 
 /*-------------------------------------------------------------------------------------------------
   Day 20: Race Condition
 -------------------------------------------------------------------------------------------------*/
 
-pub fn part1(input: &str) -> Option<String> {
-    let map = parse_input(input);
-    let cheat_count = count_cheats_that_save_time(&map, 2, 100);
+pub fn part1(input: &Input) -> Result<Answer> {
+    let cheat_count = count_cheats_that_save_time(&input.map, 2, 100)?;
 
-    Some(cheat_count.to_string())
+    Ok(cheat_count.into())
 }
 
-pub fn part2(input: &str) -> Option<String> {
-    let map = parse_input(input);
+pub fn part2(input: &Input) -> Result<Answer> {
+    let cheat_count = count_cheats_that_save_time(&input.map, 20, 100)?;
 
-    let cheat_count = count_cheats_that_save_time(&map, 20, 100);
-
-    Some(cheat_count.to_string())
+    Ok(cheat_count.into())
 }
 
 /*--------------------------------------------------------------------------------------
   Core
 --------------------------------------------------------------------------------------*/
 
+const DAY: Day = 20;
+
 type Index = i16;
 type Offset = GridIndex<Index>;
 
 type Time = i16;
 type CheatCount = usize;
 
-fn parse_input(input: &str) -> GridMap<Index, char> {
-    input.into()
+pub struct Input {
+    map: GridMap<Index, char>,
+}
+
+impl Input {
+    pub fn parse(input: &str) -> Result<Self> {
+        let parser = InputParser::new(DAY, input);
+        parser.rectangular(input)?;
+
+        let map: GridMap<Index, char> = input.into();
+        for (tile, name) in [('S', "start"), ('E', "end")] {
+            if map.find(|&c| c == tile).is_none() {
+                return Err(parser
+                    .error_at_end(format!("Missing {name} tile {tile:?}"))
+                    .into());
+            }
+        }
+
+        Ok(Self { map })
+    }
 }
 
 fn count_cheats_that_save_time(
     map: &GridMap<Index, char>,
     cheat_max_time: Time,
     min_savings: Time,
-) -> CheatCount {
-    let course = map_course(map);
+) -> Result<CheatCount, SolutionError> {
+    let course = {
+        let _span = span("map_course");
+        map_course(map)?
+    };
 
-    let course_index: HashMap<GridIndex<Index>, Time> = course
-        .iter()
-        .enumerate()
-        .map(|(time, position)| (*position, time.try_into().unwrap()))
-        .collect();
+    let course_index: HashMap<GridIndex<Index>, Time> = {
+        let _span = span("index_course");
+        course
+            .iter()
+            .enumerate()
+            .map(|(time, position)| (*position, time.try_into().unwrap()))
+            .collect()
+    };
 
-    let cheats: Vec<Cheat> = course
-        .iter()
-        .flat_map(|start| find_cheats(map, &course_index, *start, cheat_max_time))
-        .collect();
+    let cheats: Vec<Cheat> = {
+        let _span = span("find_cheats");
+        course
+            .iter()
+            .flat_map(|start| find_cheats(map, &course_index, *start, cheat_max_time))
+            .collect()
+    };
 
+    let _span = span("tally_savings");
     let savings: BTreeMap<Time, CheatCount> =
         cheats
             .iter()
@@ -69,48 +98,33 @@ fn count_cheats_that_save_time(
         log::debug!("There are {count} cheats that save {time} picoseconds.");
     }
 
-    savings
+    Ok(savings
         .iter()
         .filter_map(|(savings, count)| (*savings >= min_savings).then_some(*count))
-        .sum()
+        .sum())
 }
 
 /*-----------------------------------------------------------------------------
   Map Course
 -----------------------------------------------------------------------------*/
 
-fn map_course(map: &GridMap<Index, char>) -> Vec<GridIndex<Index>> {
+fn map_course(map: &GridMap<Index, char>) -> Result<Vec<GridIndex<Index>>, SolutionError> {
     let start = map.find(|&c| c == 'S').unwrap();
     let goal = map.find(|&c| c == 'E').unwrap();
-    let mut position = start;
-    let mut course = Vec::new();
-    while position != goal {
-        course.push(position);
-        position = next_position(map, &course);
-    }
-    course.push(goal);
-    course
-}
-
-fn next_position(map: &GridMap<Index, char>, course: &[GridIndex<Index>]) -> GridIndex<Index> {
-    let current_position = course.last().unwrap();
-    let previous_position = if course.len() > 2 {
-        course.get(course.len() - 2).unwrap()
-    } else {
-        current_position
-    };
-    for direction in GridDirection::iter() {
-        if let Some(position) = map.project_direction(*current_position, direction) {
-            if position == *previous_position {
-                continue;
-            }
 
-            if position_is_track(map, position) {
-                return position;
-            }
-        }
-    }
-    panic!("No next position found!");
+    let search = bfs(
+        start,
+        |&position| {
+            map.neighbors(position)
+                .filter(|(_, &tile)| tile != '#')
+                .map(|(next_position, _)| next_position)
+                .collect::<Vec<_>>()
+        },
+        |&position| position == goal,
+        Predecessors::First,
+    );
+
+    search.path().ok_or(SolutionError::NoSolution)
 }
 
 fn position_is_track(map: &GridMap<Index, char>, position: GridIndex<Index>) -> bool {
@@ -184,17 +198,27 @@ struct Cheat {
   CLI
 -------------------------------------------------------------------------------------------------*/
 
-#[derive(clap::Subcommand)]
-#[command(long_about = "Day 20: Race Condition")]
-pub enum Args {
-    Part1 { input: PathBuf },
-    Part2 { input: PathBuf },
-}
+pub struct Day20;
 
-pub fn main(args: Args) -> Option<String> {
-    match args {
-        Args::Part1 { input } => part1(&get_input(&input)),
-        Args::Part2 { input } => part2(&get_input(&input)),
+impl Solution for Day20 {
+    fn day(&self) -> Day {
+        DAY
+    }
+
+    fn title(&self) -> &'static str {
+        "Race Condition"
+    }
+
+    fn parse(&self, input: &str) -> Result<ParsedInput> {
+        Ok(ParsedInput::new(Input::parse(input)?))
+    }
+
+    fn part1(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part1(input.get()?)
+    }
+
+    fn part2(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part2(input.get()?)
     }
 }
 
@@ -205,23 +229,30 @@ pub fn main(args: Args) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::get_answer;
 
     #[test]
-    #[cfg_attr(not(feature = "slow_tests"), ignore)]
-    fn test_part1_solution() {
-        assert_eq!(
-            part1(&get_input("../data/day20/input.txt")),
-            get_answer("../data/day20/input-part1-answer.txt")
-        );
+    fn test_unreachable_end() {
+        let input = Input::parse("#####\n#S#E#\n#####\n").unwrap();
+        for error in [part1(&input).unwrap_err(), part2(&input).unwrap_err()] {
+            assert!(matches!(
+                error.downcast_ref::<SolutionError>(),
+                Some(SolutionError::NoSolution)
+            ));
+        }
     }
 
     #[test]
-    #[cfg_attr(not(feature = "slow_tests"), ignore)]
-    fn test_part2_solution() {
+    fn test_parse_input_errors() {
+        let error = Input::parse("#####\n#S.E\n#####\n").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "Day 20, line 2, column 1: Expected 5 columns, found 4 (\"#S.E\")"
+        );
+
+        let error = Input::parse("#####\n#S..#\n#####\n").err().unwrap();
         assert_eq!(
-            part2(&get_input("../data/day20/input.txt")),
-            get_answer("../data/day20/input-part2-answer.txt")
+            error.to_string(),
+            "Day 20, line 4, column 1: Missing end tile 'E'"
         );
     }
 }
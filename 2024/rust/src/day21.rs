@@ -1,32 +1,29 @@
-use crate::get_input;
+use crate::{Answer, Day, InputParser, Parameters, ParsedInput, Solution};
+use anyhow::Result;
 use cached::proc_macro::cached;
 use std::collections::HashMap;
-use std::path::PathBuf;
 use std::sync::OnceLock;
 
 /*-------------------------------------------------------------------------------------------------
   Day 21: Keypad Conundrum
 -------------------------------------------------------------------------------------------------*/
 
-pub fn part1(input: &str) -> Option<String> {
-    let codes = parse_input(input);
-
+pub fn part1(input: &Input) -> Result<Answer> {
     let numpad = Box::new(Keypad::new(KeypadType::NumPad, "Robot0", None));
     let dpad1 = Box::new(Keypad::new(KeypadType::DPad, "Robot1", Some(numpad)));
     let mut dpad2 = Box::new(Keypad::new(KeypadType::DPad, "Robot2", Some(dpad1)));
 
-    let code_complexity: Complexity = codes
+    let code_complexity: Complexity = input
+        .codes
         .iter()
         .map(|code| (code, dpad2.enter_code(code)))
         .map(|(code, moves)| calculate_complexity(code, moves.len()))
         .sum();
 
-    Some(code_complexity.to_string())
+    Ok(code_complexity.into())
 }
 
-pub fn part2(input: &str) -> Option<String> {
-    let codes = parse_input(input);
-
+pub fn part2(input: &Input) -> Result<Answer> {
     let mut previous_keypad = None;
     for n in (0..25).rev() {
         previous_keypad = Some(Box::new(Keypad::new(
@@ -38,7 +35,8 @@ pub fn part2(input: &str) -> Option<String> {
 
     let mut final_keypad = Box::new(Keypad::new(KeypadType::NumPad, "Robot", previous_keypad));
 
-    let code_complexity: Complexity = codes
+    let code_complexity: Complexity = input
+        .codes
         .iter()
         .map(|code| {
             let move_count: MoveCount = code
@@ -49,13 +47,15 @@ pub fn part2(input: &str) -> Option<String> {
         })
         .sum();
 
-    Some(code_complexity.to_string())
+    Ok(code_complexity.into())
 }
 
 /*--------------------------------------------------------------------------------------
   Core
 --------------------------------------------------------------------------------------*/
 
+const DAY: Day = 21;
+
 type Code = String;
 type Button = char;
 type Position = (isize, isize);
@@ -64,8 +64,28 @@ type Moves = Vec<Move>;
 type Complexity = usize;
 type MoveCount = Complexity;
 
-fn parse_input(input: &str) -> Vec<Code> {
-    input.lines().map(|line| line.to_string()).collect()
+pub struct Input {
+    codes: Vec<Code>,
+}
+
+impl Input {
+    pub fn parse(input: &str) -> Result<Self> {
+        let parser = InputParser::new(DAY, input);
+
+        let codes = input
+            .lines()
+            .map(|line| {
+                // Codes are a numeric value followed by the activate button
+                let code_value = line
+                    .strip_suffix('A')
+                    .ok_or_else(|| parser.error(line, "Code must end with 'A'"))?;
+                parser.parse::<Complexity>(code_value)?;
+                Ok(line.to_string())
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { codes })
+    }
 }
 
 fn calculate_complexity(code: &str, move_count: MoveCount) -> Complexity {
@@ -333,17 +353,27 @@ fn right_down_up_left(start: Position, end: Position) -> Moves {
   CLI
 -------------------------------------------------------------------------------------------------*/
 
-#[derive(clap::Subcommand)]
-#[command(long_about = "Day 21: Keypad Conundrum")]
-pub enum Args {
-    Part1 { input: PathBuf },
-    Part2 { input: PathBuf },
-}
+pub struct Day21;
+
+impl Solution for Day21 {
+    fn day(&self) -> Day {
+        DAY
+    }
+
+    fn title(&self) -> &'static str {
+        "Keypad Conundrum"
+    }
 
-pub fn main(args: Args) -> Option<String> {
-    match args {
-        Args::Part1 { input } => part1(&get_input(&input)),
-        Args::Part2 { input } => part2(&get_input(&input)),
+    fn parse(&self, input: &str) -> Result<ParsedInput> {
+        Ok(ParsedInput::new(Input::parse(input)?))
+    }
+
+    fn part1(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part1(input.get()?)
+    }
+
+    fn part2(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part2(input.get()?)
     }
 }
 
@@ -354,29 +384,19 @@ pub fn main(args: Args) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::get_answer;
 
     #[test]
-    fn test_part1_example() {
+    fn test_parse_input_errors() {
+        let error = Input::parse("029A\n980\n").err().unwrap();
         assert_eq!(
-            part1(&get_input("../data/day21/example.txt")),
-            get_answer("../data/day21/example-part1-answer.txt")
+            error.to_string(),
+            "Day 21, line 2, column 1: Code must end with 'A' (\"980\")"
         );
-    }
 
-    #[test]
-    fn test_part1_solution() {
-        assert_eq!(
-            part1(&get_input("../data/day21/input.txt")),
-            get_answer("../data/day21/input-part1-answer.txt")
-        );
-    }
-
-    #[test]
-    fn test_part2_solution() {
+        let error = Input::parse("02xA\n").err().unwrap();
         assert_eq!(
-            part2(&get_input("../data/day21/input.txt")),
-            get_answer("../data/day21/input-part2-answer.txt")
+            error.to_string(),
+            "Day 21, line 1, column 1: invalid digit found in string (\"02x\")"
         );
     }
 }
@@ -1,50 +1,63 @@
-use crate::get_input;
+use crate::{Answer, Day, InputParser, Parameters, ParsedInput, Solution};
+use anyhow::Result;
 use std::collections::{HashMap, HashSet};
-use std::path::PathBuf;
 use std::vec;
 
 /*-------------------------------------------------------------------------------------------------
   Day 22: Monkey Market
 -------------------------------------------------------------------------------------------------*/
 
-pub fn part1(input: &str) -> Option<String> {
-    let secrets = parse_input(input);
-
-    let secrets_sum: Answer = secrets
-        .into_iter()
-        .map(|secret| {
+pub fn part1(input: &Input) -> Result<Answer> {
+    let secrets_sum: Total = input
+        .secrets
+        .iter()
+        .map(|&secret| {
             let mut secret = secret;
             for _ in 0..2000 {
                 secret = evolve_secret(secret);
             }
-            secret as Answer
+            secret as Total
         })
         .sum();
 
-    Some(secrets_sum.to_string())
+    Ok(secrets_sum.into())
 }
 
-pub fn part2(input: &str) -> Option<String> {
-    let secrets = parse_input(input);
-    let max_bananas = find_maximum_bananas(secrets);
+pub fn part2(input: &Input) -> Result<Answer> {
+    let max_bananas = find_maximum_bananas(&input.secrets);
 
-    Some(max_bananas.to_string())
+    Ok(max_bananas.into())
 }
 
 /*--------------------------------------------------------------------------------------
   Core
 --------------------------------------------------------------------------------------*/
 
+const DAY: Day = 22;
+
 type Secret = u64;
 type Secrets = Vec<Secret>;
 type Price = i8;
 type Prices = Vec<Price>;
 type PriceChange = Price;
 type Sequence = [PriceChange; 4];
-type Answer = u64;
+type Total = u64;
+
+pub struct Input {
+    secrets: Secrets,
+}
 
-fn parse_input(input: &str) -> Secrets {
-    input.lines().map(|line| line.parse().unwrap()).collect()
+impl Input {
+    pub fn parse(input: &str) -> Result<Self> {
+        let parser = InputParser::new(DAY, input);
+
+        let secrets = input
+            .lines()
+            .map(|line| parser.parse(line))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { secrets })
+    }
 }
 
 fn evolve_secret(secret: Secret) -> Secret {
@@ -53,12 +66,12 @@ fn evolve_secret(secret: Secret) -> Secret {
     ((secret * 2048) ^ secret) % 16777216
 }
 
-fn find_maximum_bananas(secrets: Secrets) -> Answer {
-    let mut bananas_by_sequence: HashMap<Sequence, Answer> = HashMap::new();
+fn find_maximum_bananas(secrets: &[Secret]) -> Total {
+    let mut bananas_by_sequence: HashMap<Sequence, Total> = HashMap::new();
     let mut prices: Prices = vec![0; 2000];
     let mut seller_sequences: HashSet<Sequence> = HashSet::new();
 
-    for secret in secrets {
+    for &secret in secrets {
         // Calculate the prices for this buyer's secret
         let mut secret = secret;
         for price in prices.iter_mut() {
@@ -79,7 +92,7 @@ fn find_maximum_bananas(secrets: Secrets) -> Answer {
             // from this seller
             if !seller_sequences.contains(&sequence) {
                 let bananas = bananas_by_sequence.entry(sequence).or_insert(0);
-                *bananas += window[4] as Answer;
+                *bananas += window[4] as Total;
                 seller_sequences.insert(sequence);
             }
         }
@@ -95,17 +108,27 @@ fn find_maximum_bananas(secrets: Secrets) -> Answer {
   CLI
 -------------------------------------------------------------------------------------------------*/
 
-#[derive(clap::Subcommand)]
-#[command(long_about = "Day 22: Monkey Market")]
-pub enum Args {
-    Part1 { input: PathBuf },
-    Part2 { input: PathBuf },
-}
+pub struct Day22;
+
+impl Solution for Day22 {
+    fn day(&self) -> Day {
+        DAY
+    }
+
+    fn title(&self) -> &'static str {
+        "Monkey Market"
+    }
+
+    fn parse(&self, input: &str) -> Result<ParsedInput> {
+        Ok(ParsedInput::new(Input::parse(input)?))
+    }
+
+    fn part1(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part1(input.get()?)
+    }
 
-pub fn main(args: Args) -> Option<String> {
-    match args {
-        Args::Part1 { input } => part1(&get_input(&input)),
-        Args::Part2 { input } => part2(&get_input(&input)),
+    fn part2(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part2(input.get()?)
     }
 }
 
@@ -116,38 +139,13 @@ pub fn main(args: Args) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::get_answer;
-
-    #[test]
-    fn test_example0_part1() {
-        assert_eq!(
-            part1(&get_input("../data/day22/example0.txt")),
-            get_answer("../data/day22/example0-part1-answer.txt")
-        );
-    }
-
-    #[test]
-    fn test_part1_solution() {
-        assert_eq!(
-            part1(&get_input("../data/day22/input.txt")),
-            get_answer("../data/day22/input-part1-answer.txt")
-        );
-    }
-
-    #[test]
-    fn test_example1_part2() {
-        assert_eq!(
-            part2(&get_input("../data/day22/example1.txt")),
-            get_answer("../data/day22/example1-part2-answer.txt")
-        );
-    }
 
     #[test]
-    #[cfg_attr(not(feature = "slow_tests"), ignore)]
-    fn test_part2_solution() {
+    fn test_parse_input_errors() {
+        let error = Input::parse("1\n10\nx\n").err().unwrap();
         assert_eq!(
-            part2(&get_input("../data/day22/input.txt")),
-            get_answer("../data/day22/input-part2-answer.txt")
+            error.to_string(),
+            "Day 22, line 3, column 1: invalid digit found in string (\"x\")"
         );
     }
 }
@@ -1,68 +1,75 @@
-use crate::get_input;
+use crate::{Answer, Day, InputParser, Parameters, ParsedInput, Solution};
+use anyhow::Result;
 use std::collections::{HashMap, HashSet};
 use std::iter::once;
-use std::path::PathBuf;
-use std::rc::Rc;
+use std::sync::Arc;
 
 /*-------------------------------------------------------------------------------------------------
   Day 23: LAN Party
 -------------------------------------------------------------------------------------------------*/
 
-pub fn part1(input: &str) -> Option<String> {
-    let (_, connections) = parse_input(input);
-    let node_map = build_node_graph(connections);
+pub fn part1(input: &Input) -> Result<Answer> {
+    let node_map = build_node_graph(&input.connections);
 
     let network_count = identify_sets_of_three_connected_nodes(&node_map)
         .iter()
         .filter(|nodes| nodes.iter().any(|node| node.starts_with("t")))
         .count();
 
-    Some(network_count.to_string())
+    Ok(network_count.into())
 }
 
-pub fn part2(input: &str) -> Option<String> {
-    let (_, connections) = parse_input(input);
-    let node_map = build_node_graph(connections);
+pub fn part2(input: &Input) -> Result<Answer> {
+    let node_map = build_node_graph(&input.connections);
 
     let largest_network = find_largest_network(&node_map);
 
-    Some(largest_network.join(","))
+    Ok(largest_network.iter().collect())
 }
 
 /*--------------------------------------------------------------------------------------
   Core
 --------------------------------------------------------------------------------------*/
 
-type Node = Rc<str>;
+const DAY: Day = 23;
+
+type Node = Arc<str>;
 type NodeTriple = [Node; 3];
 type Network = Vec<Node>;
 type Connection = (Node, Node);
 type NodeSet = HashSet<Node>;
 type NodeMap = HashMap<Node, NodeSet>;
 
-fn parse_input(input: &str) -> (NodeSet, Vec<Connection>) {
-    let mut nodes = NodeSet::new();
-
-    let connections: Vec<Connection> = input
-        .lines()
-        .map(|line| {
-            let mut split = line.split('-');
-            let n1 = split.next().unwrap();
-            let n2 = split.next().unwrap();
-            let n1 = git_or_insert(&mut nodes, n1);
-            let n2 = git_or_insert(&mut nodes, n2);
-            (n1, n2)
-        })
-        .collect();
-
-    (nodes, connections)
+pub struct Input {
+    connections: Vec<Connection>,
+}
+
+impl Input {
+    pub fn parse(input: &str) -> Result<Self> {
+        let parser = InputParser::new(DAY, input);
+        let mut nodes = NodeSet::new();
+
+        let connections = input
+            .lines()
+            .map(|line| {
+                let mut split = line.split('-');
+                let n1 = parser.required(split.next(), line)?;
+                let n2 = parser.required(split.next(), line)?;
+                let n1 = git_or_insert(&mut nodes, n1);
+                let n2 = git_or_insert(&mut nodes, n2);
+                Ok((n1, n2))
+            })
+            .collect::<Result<Vec<Connection>>>()?;
+
+        Ok(Self { connections })
+    }
 }
 
 fn git_or_insert(node_set: &mut NodeSet, node: &str) -> Node {
     if let Some(existing_node) = node_set.get(node) {
         existing_node.clone()
     } else {
-        let new_node: Rc<str> = Rc::from(node);
+        let new_node: Arc<str> = Arc::from(node);
         node_set.insert(new_node.clone());
         new_node
     }
@@ -72,7 +79,7 @@ fn git_or_insert(node_set: &mut NodeSet, node: &str) -> Node {
   Build Node Graph
 -----------------------------------------------------------------------------*/
 
-fn build_node_graph(connections: Vec<Connection>) -> NodeMap {
+fn build_node_graph(connections: &[Connection]) -> NodeMap {
     let mut node_map = NodeMap::new();
 
     for (n1, n2) in connections {
@@ -155,17 +162,27 @@ fn find_largest_network(node_map: &NodeMap) -> Network {
   CLI
 -------------------------------------------------------------------------------------------------*/
 
-#[derive(clap::Subcommand)]
-#[command(long_about = "Day 23: LAN Party")]
-pub enum Args {
-    Part1 { input: PathBuf },
-    Part2 { input: PathBuf },
-}
+pub struct Day23;
+
+impl Solution for Day23 {
+    fn day(&self) -> Day {
+        DAY
+    }
 
-pub fn main(args: Args) -> Option<String> {
-    match args {
-        Args::Part1 { input } => part1(&get_input(&input)),
-        Args::Part2 { input } => part2(&get_input(&input)),
+    fn title(&self) -> &'static str {
+        "LAN Party"
+    }
+
+    fn parse(&self, input: &str) -> Result<ParsedInput> {
+        Ok(ParsedInput::new(Input::parse(input)?))
+    }
+
+    fn part1(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part1(input.get()?)
+    }
+
+    fn part2(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part2(input.get()?)
     }
 }
 
@@ -176,38 +193,13 @@ pub fn main(args: Args) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::get_answer;
-
-    #[test]
-    fn test_example_part1() {
-        assert_eq!(
-            part1(&get_input("../data/day23/example.txt")),
-            get_answer("../data/day23/example-part1-answer.txt")
-        );
-    }
-
-    #[test]
-    fn test_part1_solution() {
-        assert_eq!(
-            part1(&get_input("../data/day23/input.txt")),
-            get_answer("../data/day23/input-part1-answer.txt")
-        );
-    }
-
-    #[test]
-    fn test_example_part2() {
-        assert_eq!(
-            part2(&get_input("../data/day23/example.txt")),
-            get_answer("../data/day23/example-part2-answer.txt")
-        );
-    }
 
     #[test]
-    #[cfg_attr(not(feature = "slow_tests"), ignore)]
-    fn test_part2_solution() {
+    fn test_parse_input_errors() {
+        let error = Input::parse("kh-tc\nqp\n").err().unwrap();
         assert_eq!(
-            part2(&get_input("../data/day23/input.txt")),
-            get_answer("../data/day23/input-part2-answer.txt")
+            error.to_string(),
+            "Day 23, line 2, column 1: Missing value (\"qp\")"
         );
     }
 }
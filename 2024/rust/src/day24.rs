@@ -1,39 +1,39 @@
-use crate::get_input;
-use core::panic;
-use itertools::Itertools;
+use crate::{Answer, Day, InputParser, Parameters, ParsedInput, Solution, SolutionError};
+use anyhow::{anyhow, Result};
 use std::collections::{BTreeSet, HashMap, VecDeque};
 use std::fmt::{self, Display, Formatter};
 use std::hash::Hash;
 use std::ops::{BitAnd, BitOr, BitXor};
-use std::path::PathBuf;
-use std::rc::Rc;
+use std::str::FromStr;
+use std::sync::Arc;
 
 /*-------------------------------------------------------------------------------------------------
   Day 24: Crossed Wires
 -------------------------------------------------------------------------------------------------*/
 
-pub fn part1(input: &str) -> Option<String> {
-    let (mut wires, gates) = parse_input(input);
-    process_gates(&mut wires, &gates);
+pub fn part1(input: &Input) -> Result<Answer> {
+    let mut wires = input.wires.clone();
+    process_gates(&mut wires, &input.gates);
     let z_value = combine_bits(&wires, "z");
 
-    Some(z_value.to_string())
+    Ok(z_value.into())
 }
 
-pub fn part2(input: &str) -> Option<String> {
-    let (_, gates) = parse_input(input);
-    let crossed_wires = find_crossed_wires(&gates);
+pub fn part2(input: &Input) -> Result<Answer> {
+    let crossed_wires = find_crossed_wires(&input.gates)?;
 
     let crossed_wires: BTreeSet<Wire> = crossed_wires.iter().flatten().cloned().collect();
 
-    Some(crossed_wires.iter().join(","))
+    Ok(crossed_wires.iter().collect())
 }
 
 /*--------------------------------------------------------------------------------------
   Core
 --------------------------------------------------------------------------------------*/
 
-type Wire = Rc<str>;
+const DAY: Day = 24;
+
+type Wire = Arc<str>;
 type InputWire = Wire;
 type OutputWire = Wire;
 type Inputs = [InputWire; 2];
@@ -65,39 +65,51 @@ enum GateRole {
     COr,
 }
 
-fn parse_input(input: &str) -> (Wires, Gates) {
-    let blank_line_index = input.lines().position(|line| line.is_empty()).unwrap();
-
-    let wires: Wires = input
-        .lines()
-        .take(blank_line_index)
-        .map(|line| {
-            let mut parts = line.split(": ");
-            let wire = parts.next().unwrap().into();
-            let value = match parts.next().unwrap() {
-                "0" => false,
-                "1" => true,
-                _ => panic!("Invalid value"),
-            };
-            (wire, value)
-        })
-        .collect();
-
-    let gates: Gates = input
-        .lines()
-        .skip(blank_line_index + 1)
-        .map(|line| {
-            let mut parts = line.split_whitespace();
-            let input0 = parts.next().unwrap();
-            let operation = parts.next().unwrap();
-            let input1 = parts.next().unwrap();
-            let _ = parts.next();
-            let output = parts.next().unwrap().into();
-            (Gate::new(input0, input1, operation), output)
-        })
-        .collect();
+pub struct Input {
+    wires: Wires,
+    gates: Gates,
+}
 
-    (wires, gates)
+impl Input {
+    pub fn parse(input: &str) -> Result<Self> {
+        let parser = InputParser::new(DAY, input);
+
+        let blank_line_index = input
+            .lines()
+            .position(|line| line.is_empty())
+            .ok_or_else(|| parser.error_at_end("Missing blank line after the wire values"))?;
+
+        let wires = input
+            .lines()
+            .take(blank_line_index)
+            .map(|line| {
+                let mut parts = line.split(": ");
+                let wire = parser.required(parts.next(), line)?.into();
+                let value = match parser.required(parts.next(), line)? {
+                    "0" => false,
+                    "1" => true,
+                    value => return Err(parser.error(value, "Invalid wire value").into()),
+                };
+                Ok((wire, value))
+            })
+            .collect::<Result<Wires>>()?;
+
+        let gates = input
+            .lines()
+            .skip(blank_line_index + 1)
+            .map(|line| {
+                let mut parts = line.split_whitespace();
+                let input0 = parser.required(parts.next(), line)?;
+                let operation = parser.parse(parser.required(parts.next(), line)?)?;
+                let input1 = parser.required(parts.next(), line)?;
+                let _ = parts.next();
+                let output = parser.required(parts.next(), line)?.into();
+                Ok((Gate::new(input0, input1, operation), output))
+            })
+            .collect::<Result<Gates>>()?;
+
+        Ok(Self { wires, gates })
+    }
 }
 
 fn process_gates(wires: &mut Wires, gates: &Gates) {
@@ -131,20 +143,24 @@ fn combine_bits(wires: &Wires, selector: &str) -> Output {
         })
 }
 
-fn find_crossed_wires(gates: &Gates) -> Vec<Inputs> {
+// Inputs that aren't wired as a ripple-carry adder of 45-bit numbers have no solution
+fn find_crossed_wires(gates: &Gates) -> Result<Vec<Inputs>> {
     let mut crossed_wires: Vec<Inputs> = Vec::new();
 
     // First bit
-    let xy_xor_gate = Gate::new("x00", "y00", "XOR");
-    let xy_and_gate = Gate::new("x00", "y00", "AND");
+    let xy_xor_gate = Gate::new("x00", "y00", GateOperation::Xor);
+    let xy_and_gate = Gate::new("x00", "y00", GateOperation::And);
     let z_wire: Wire = "z00".into();
-    let z_xor_out_wire = gates.get(&xy_xor_gate).unwrap();
+    let z_xor_out_wire = gates.get(&xy_xor_gate).ok_or(SolutionError::NoSolution)?;
 
     if &z_wire != z_xor_out_wire {
         crossed_wires.push([z_xor_out_wire.clone(), z_wire.clone()]);
     };
 
-    let mut c_wire = gates.get(&xy_and_gate).unwrap().clone();
+    let mut c_wire = gates
+        .get(&xy_and_gate)
+        .ok_or(SolutionError::NoSolution)?
+        .clone();
 
     // Middle bits
     for bit in 1..45u8 {
@@ -152,18 +168,18 @@ fn find_crossed_wires(gates: &Gates) -> Vec<Inputs> {
         let y_wire: Wire = format!("y{:02}", bit).into();
         let z_wire: Wire = format!("z{:02}", bit).into();
 
-        let xy_xor_gate = Gate::from(inputs(x_wire.clone(), y_wire.clone()), "XOR".into());
+        let xy_xor_gate = Gate::from(inputs(x_wire.clone(), y_wire.clone()), GateOperation::Xor);
         let (xy_xor_gate, xy_xor_out_wire) = {
-            let (gate, out_wire, crossed_pair) = get_gate(gates, &xy_xor_gate);
+            let (gate, out_wire, crossed_pair) = get_gate(gates, &xy_xor_gate)?;
             if let Some(crossed_pair) = crossed_pair {
                 crossed_wires.push(crossed_pair);
             };
             (gate, out_wire)
         };
 
-        let xy_and_gate = Gate::from(inputs(x_wire.clone(), y_wire.clone()), "AND".into());
+        let xy_and_gate = Gate::from(inputs(x_wire.clone(), y_wire.clone()), GateOperation::And);
         let (xy_and_gate, xy_and_out_wire) = {
-            let (gate, out_wire, crossed_pair) = get_gate(gates, &xy_and_gate);
+            let (gate, out_wire, crossed_pair) = get_gate(gates, &xy_and_gate)?;
             if let Some(crossed_pair) = crossed_pair {
                 crossed_wires.push(crossed_pair);
             };
@@ -172,10 +188,10 @@ fn find_crossed_wires(gates: &Gates) -> Vec<Inputs> {
 
         let z_xor_gate = Gate::from(
             inputs(xy_xor_out_wire.clone(), c_wire.clone()),
-            "XOR".into(),
+            GateOperation::Xor,
         );
         let (z_xor_gate, z_xor_out_wire) = {
-            let (gate, out_wire, crossed_pair) = get_gate(gates, &z_xor_gate);
+            let (gate, out_wire, crossed_pair) = get_gate(gates, &z_xor_gate)?;
             if let Some(crossed_pair) = crossed_pair {
                 crossed_wires.push(crossed_pair);
             };
@@ -188,10 +204,10 @@ fn find_crossed_wires(gates: &Gates) -> Vec<Inputs> {
 
         let c_and_gate = Gate::from(
             inputs(xy_xor_out_wire.clone(), c_wire.clone()),
-            "AND".into(),
+            GateOperation::And,
         );
         let (c_and_gate, c_and_out_wire) = {
-            let (gate, out_wire, crossed_pair) = get_gate(gates, &c_and_gate);
+            let (gate, out_wire, crossed_pair) = get_gate(gates, &c_and_gate)?;
             if let Some(crossed_pair) = crossed_pair {
                 crossed_wires.push(crossed_pair);
             };
@@ -200,10 +216,10 @@ fn find_crossed_wires(gates: &Gates) -> Vec<Inputs> {
 
         let c_or_gate = Gate::from(
             inputs(xy_and_out_wire.clone(), c_and_out_wire.clone()),
-            "OR".into(),
+            GateOperation::Or,
         );
         let (c_or_gate, c_or_out_wire) = {
-            let (gate, out_wire, crossed_pair) = get_gate(gates, &c_or_gate);
+            let (gate, out_wire, crossed_pair) = get_gate(gates, &c_or_gate)?;
             if let Some(crossed_pair) = crossed_pair {
                 crossed_wires.push(crossed_pair);
             };
@@ -230,13 +246,13 @@ fn find_crossed_wires(gates: &Gates) -> Vec<Inputs> {
     };
 
     log::debug!("crossed_wires: {:?}", crossed_wires);
-    crossed_wires
+    Ok(crossed_wires)
 }
 
-fn get_gate(gates: &Gates, gate: &Gate) -> (Gate, Wire, Option<Inputs>) {
+fn get_gate(gates: &Gates, gate: &Gate) -> Result<(Gate, Wire, Option<Inputs>)> {
     if let Some(out_wire) = gates.get(gate) {
         // Gate exists; return the gate and current output wire
-        return (gate.clone(), out_wire.clone(), None);
+        return Ok((gate.clone(), out_wire.clone(), None));
     };
 
     // Gate does not exist = one of the input wires must be crossed
@@ -245,25 +261,26 @@ fn get_gate(gates: &Gates, gate: &Gate) -> (Gate, Wire, Option<Inputs>) {
     if let Some((correct_gate, other_input, out_wire)) =
         find_gate(gates, &gate.inputs[0], gate.operation)
     {
-        return (
+        return Ok((
             correct_gate,
             out_wire,
             Some([gate.inputs[1].clone(), other_input.clone()]),
-        );
+        ));
     };
 
     // See if we can find a matching gate with the second input wire and gate operation
     if let Some((correct_gate, other_input, out_wire)) =
         find_gate(gates, &gate.inputs[1], gate.operation)
     {
-        return (
+        return Ok((
             correct_gate,
             out_wire,
             Some([gate.inputs[0].clone(), other_input.clone()]),
-        );
+        ));
     };
 
-    panic!("Could not find gate! Gate: {:?}", gate);
+    log::debug!("Could not find gate: {:?}", gate);
+    Err(SolutionError::NoSolution.into())
 }
 
 fn find_gate(
@@ -310,9 +327,8 @@ fn wire_bit(wire: &Wire) -> Option<Bit> {
 -----------------------------------------------------------------------------*/
 
 impl Gate {
-    fn new(input0: &str, input1: &str, operation: &str) -> Self {
+    fn new(input0: &str, input1: &str, operation: GateOperation) -> Self {
         let inputs = inputs(input0.into(), input1.into());
-        let operation = operation.into();
         let role = GateRole::new(&inputs, operation);
 
         Gate {
@@ -372,13 +388,15 @@ impl Display for GateOperation {
     }
 }
 
-impl From<&str> for GateOperation {
-    fn from(s: &str) -> Self {
+impl FromStr for GateOperation {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "AND" => GateOperation::And,
-            "OR" => GateOperation::Or,
-            "XOR" => GateOperation::Xor,
-            _ => panic!("Invalid gate type"),
+            "AND" => Ok(GateOperation::And),
+            "OR" => Ok(GateOperation::Or),
+            "XOR" => Ok(GateOperation::Xor),
+            _ => Err(anyhow!("Invalid gate type")),
         }
     }
 }
@@ -389,11 +407,11 @@ impl From<&str> for GateOperation {
 
 impl GateRole {
     fn new(inputs: &Inputs, operation: GateOperation) -> Self {
-        let input0 = inputs[0].chars().next().unwrap();
-        let input1 = inputs[1].chars().next().unwrap();
+        let input0 = inputs[0].chars().next();
+        let input1 = inputs[1].chars().next();
         match (input0, input1, operation) {
-            ('x', 'y', GateOperation::Xor) => GateRole::XyXor,
-            ('x', 'y', GateOperation::And) => GateRole::XyAnd,
+            (Some('x'), Some('y'), GateOperation::Xor) => GateRole::XyXor,
+            (Some('x'), Some('y'), GateOperation::And) => GateRole::XyAnd,
             (_, _, GateOperation::Xor) => GateRole::ZXor,
             (_, _, GateOperation::And) => GateRole::CAnd,
             (_, _, GateOperation::Or) => GateRole::COr,
@@ -405,17 +423,27 @@ impl GateRole {
   CLI
 -------------------------------------------------------------------------------------------------*/
 
-#[derive(clap::Subcommand)]
-#[command(long_about = "Day 24: Crossed Wires")]
-pub enum Args {
-    Part1 { input: PathBuf },
-    Part2 { input: PathBuf },
-}
+pub struct Day24;
 
-pub fn main(args: Args) -> Option<String> {
-    match args {
-        Args::Part1 { input } => part1(&get_input(&input)),
-        Args::Part2 { input } => part2(&get_input(&input)),
+impl Solution for Day24 {
+    fn day(&self) -> Day {
+        DAY
+    }
+
+    fn title(&self) -> &'static str {
+        "Crossed Wires"
+    }
+
+    fn parse(&self, input: &str) -> Result<ParsedInput> {
+        Ok(ParsedInput::new(Input::parse(input)?))
+    }
+
+    fn part1(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part1(input.get()?)
+    }
+
+    fn part2(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part2(input.get()?)
     }
 }
 
@@ -426,37 +454,39 @@ pub fn main(args: Args) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::get_answer;
 
     #[test]
-    fn test_example0_part1() {
+    fn test_parse_input_errors() {
+        let error = Input::parse("x00: 1\n").err().unwrap();
         assert_eq!(
-            part1(&get_input("../data/day24/example0.txt")),
-            get_answer("../data/day24/example0-part1-answer.txt")
+            error.to_string(),
+            "Day 24, line 2, column 1: Missing blank line after the wire values"
         );
-    }
 
-    // #[test]
-    // fn test_example1_part1() {
-    //     assert_eq!(
-    //         part1("../data/day24/example1.txt"),
-    //         solution("../data/day24/example1-part1-answer.txt")
-    //     );
-    // }
+        let error = Input::parse("x00: 2\n\nx00 AND y00 -> z00\n")
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "Day 24, line 1, column 6: Invalid wire value (\"2\")"
+        );
 
-    #[test]
-    fn test_part1_solution() {
+        let error = Input::parse("x00: 1\n\nx00 NAND y00 -> z00\n")
+            .err()
+            .unwrap();
         assert_eq!(
-            part1(&get_input("../data/day24/input.txt")),
-            get_answer("../data/day24/input-part1-answer.txt")
+            error.to_string(),
+            "Day 24, line 3, column 5: Invalid gate type (\"NAND\")"
         );
     }
 
     #[test]
-    fn test_part2_solution() {
-        assert_eq!(
-            part2(&get_input("../data/day24/input.txt")),
-            get_answer("../data/day24/input-part2-answer.txt")
-        );
+    fn test_part2_without_adder() {
+        let input = Input::parse("x00: 1\ny00: 0\n\nx00 AND y00 -> z00\n").unwrap();
+        let error = part2(&input).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<SolutionError>(),
+            Some(SolutionError::NoSolution)
+        ));
     }
 }
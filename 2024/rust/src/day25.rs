@@ -1,28 +1,30 @@
-use crate::get_input;
-use core::panic;
+use crate::{
+    Answer, Day, GridMap, InputParser, Parameters, ParsedInput, Part, Solution, SolutionError,
+};
+use anyhow::Result;
 use itertools::Itertools;
-use std::path::PathBuf;
 
 /*-------------------------------------------------------------------------------------------------
   Day 25: Code Chronicle
 -------------------------------------------------------------------------------------------------*/
 
-pub fn part1(input: &str) -> Option<String> {
-    let (locks, keys) = parse_input(input);
-
-    let lock_key_pair_count = locks
+pub fn part1(input: &Input) -> Result<Answer> {
+    let lock_key_pair_count = input
+        .locks
         .iter()
-        .cartesian_product(keys.iter())
+        .cartesian_product(input.keys.iter())
         .filter(|(lock, key)| lock.iter().zip(key.iter()).all(|(l, k)| l + k <= 5))
         .count();
 
-    Some(lock_key_pair_count.to_string())
+    Ok(lock_key_pair_count.into())
 }
 
 /*--------------------------------------------------------------------------------------
   Core
 --------------------------------------------------------------------------------------*/
 
+const DAY: Day = 25;
+
 type Schematic = [u8; 5];
 type Lock = Schematic;
 type Locks = Vec<Lock>;
@@ -30,65 +32,106 @@ type Locks = Vec<Lock>;
 type Key = Schematic;
 type Keys = Vec<Key>;
 
-fn parse_input(input: &str) -> (Locks, Keys) {
-    let mut locks = Vec::new();
-    let mut keys = Vec::new();
-
-    let mut lines = input.lines().peekable();
-
-    while lines.peek().is_some() {
-        let first_line = lines.next().unwrap();
-        let (lock, key) = match first_line {
-            "#####" => (true, false),
-            "....." => (false, true),
-            _ => panic!("Invalid first line: {first_line}"),
-        };
-
-        let mut value: Schematic = [0; 5];
-        for _ in 0..5 {
-            let line = lines.next().unwrap();
-            assert_eq!(line.len(), 5);
-            for (i, c) in line.chars().enumerate() {
-                value[i] += match c {
-                    '#' => 1,
-                    '.' => 0,
-                    _ => panic!("Invalid character: {c}"),
-                };
+#[derive(Debug)]
+pub struct Input {
+    locks: Locks,
+    keys: Keys,
+}
+
+impl Input {
+    pub fn parse(input: &str) -> Result<Self> {
+        let parser = InputParser::new(DAY, input);
+
+        let mut locks = Vec::new();
+        let mut keys = Vec::new();
+
+        // Schematics are separated by blank lines
+        for block in input.split("\n\n") {
+            let mut lines = block.lines();
+
+            let first_line = next_line(&parser, block, &mut lines)?;
+            let (lock, end) = match first_line {
+                "#####" => (true, "....."),
+                "....." => (false, "#####"),
+                _ => return Err(parser.error(first_line, "Expected a lock or key").into()),
+            };
+
+            for _ in 0..5 {
+                let line = next_line(&parser, block, &mut lines)?;
+                if line.len() != 5 {
+                    return Err(parser.error(line, "Expected 5 columns").into());
+                }
+                if let Some(i) = line.find(|c| c != '#' && c != '.') {
+                    return Err(parser.char_error(line, i, "Invalid character").into());
+                }
             }
-        }
 
-        let end_line = lines.next().unwrap();
-        match (lock, key) {
-            (true, false) => {
-                assert_eq!(end_line, ".....");
-                locks.push(value);
+            let end_line = next_line(&parser, block, &mut lines)?;
+            if end_line != end {
+                return Err(parser.error(end_line, format!("Expected {end:?}")).into());
             }
-            (false, true) => {
-                assert_eq!(end_line, "#####");
+            if let Some(line) = lines.next() {
+                return Err(parser.error(line, "Expected a blank line").into());
+            }
+
+            // Each pin's height is the number of filled rows in its column, besides the lock's
+            // filled top row or the key's filled bottom row
+            let schematic: GridMap<u8, char> = block.into();
+            let mut value: Schematic = [0; 5];
+            for (height, column) in value.iter_mut().zip(schematic.columns_iter()) {
+                *height = column.filter(|&&c| c == '#').count() as u8 - 1;
+            }
+
+            if lock {
+                locks.push(value);
+            } else {
                 keys.push(value);
             }
-            _ => panic!("Invalid lock/key flags: {lock}/{key}"),
         }
 
-        let _ = lines.next(); // Skip blank line
+        Ok(Self { locks, keys })
     }
+}
 
-    (locks, keys)
+fn next_line<'i>(
+    parser: &InputParser,
+    block: &'i str,
+    lines: &mut impl Iterator<Item = &'i str>,
+) -> Result<&'i str> {
+    Ok(lines
+        .next()
+        .ok_or_else(|| parser.error(&block[block.len()..], "Incomplete schematic"))?)
 }
 
 /*-------------------------------------------------------------------------------------------------
   CLI
 -------------------------------------------------------------------------------------------------*/
 
-#[derive(clap::Subcommand)]
-#[command(long_about = "Day 25: Code Chronicle")]
-pub enum Args {
-    Part1 { input: PathBuf },
-}
+pub struct Day25;
+
+impl Solution for Day25 {
+    fn day(&self) -> Day {
+        DAY
+    }
 
-pub fn main(args: Args) -> Option<String> {
-    match args {
-        Args::Part1 { input } => part1(&get_input(&input)),
+    fn title(&self) -> &'static str {
+        "Code Chronicle"
+    }
+
+    fn parts(&self) -> &'static [Part] {
+        &[Part::Part1]
+    }
+
+    fn parse(&self, input: &str) -> Result<ParsedInput> {
+        Ok(ParsedInput::new(Input::parse(input)?))
+    }
+
+    fn part1(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part1(input.get()?)
+    }
+
+    fn part2(&self, _: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        Err(SolutionError::Unimplemented.into())
     }
 }
 
@@ -98,15 +141,13 @@ pub fn main(args: Args) -> Option<String> {
 
 #[cfg(test)]
 mod tests {
-    use std::vec;
-
     use super::*;
-    use crate::get_answer;
+    use crate::get_input;
 
     #[test]
     fn test_parse_input_file() {
-        let input = get_input("../data/day25/example.txt");
-        let (locks, keys) = parse_input(&input);
+        let input = get_input("day25/example.txt").unwrap();
+        let Input { locks, keys } = Input::parse(&input).unwrap();
 
         assert_eq!(locks, vec![[0, 5, 3, 4, 3], [1, 2, 0, 5, 3]]);
         assert_eq!(
@@ -116,18 +157,24 @@ mod tests {
     }
 
     #[test]
-    fn test_example_part1() {
+    fn test_parse_input_errors() {
+        let error = Input::parse("#####\n.#.#.\n.#x#.\n").unwrap_err();
         assert_eq!(
-            part1(&get_input("../data/day25/example.txt")),
-            get_answer("../data/day25/example-part1-answer.txt")
+            error.to_string(),
+            "Day 25, line 3, column 3: Invalid character (\"x\")"
         );
-    }
 
-    #[test]
-    fn test_part1_solution() {
+        let error = Input::parse("#####\n.#.#.\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Day 25, line 3, column 1: Incomplete schematic"
+        );
+
+        let error =
+            Input::parse("#####\n.....\n.....\n.....\n.....\n.....\n.....\n#####\n").unwrap_err();
         assert_eq!(
-            part1(&get_input("../data/day25/input.txt")),
-            get_answer("../data/day25/input-part1-answer.txt")
+            error.to_string(),
+            "Day 25, line 8, column 1: Expected a blank line (\"#####\")"
         );
     }
 }
@@ -1,117 +1,129 @@
-use crate::get_input;
+use crate::{Answer, Day, InputParser, Parameters, ParsedInput, Solution};
+use anyhow::Result;
 use regex::Regex;
-use std::path::PathBuf;
 use std::sync::OnceLock;
 
 /*-------------------------------------------------------------------------------------------------
   Day 3: Mull It Over
 -------------------------------------------------------------------------------------------------*/
 
-pub fn part1(input: &str) -> Option<String> {
-    let mul_regex = get_mul_regex();
-
-    let multiplication_sum: Number = mul_regex
-        .captures_iter(input)
-        .map(|cap| {
-            let a: Number = cap[1].parse().unwrap();
-            let b: Number = cap[2].parse().unwrap();
-            a * b
+pub fn part1(input: &Input) -> Result<Answer> {
+    let multiplication_sum: Number = input
+        .instructions
+        .iter()
+        .map(|instruction| match instruction {
+            Instruction::Multiply(a, b) => a * b,
+            _ => 0,
         })
         .sum();
 
-    Some(multiplication_sum.to_string())
+    Ok(multiplication_sum.into())
 }
 
-pub fn part2(input: &str) -> Option<String> {
-    let enabled_sections: Vec<_> = input
-        .split("do()")
-        .map(|section| section.split("don't()").next())
-        .collect();
-
-    let mul_regex = get_mul_regex();
-
-    let enabled_multiplications_sum = enabled_sections.iter().fold(0, |acc, section| {
-        acc + mul_regex
-            .captures_iter(section.unwrap())
-            .map(|cap| {
-                let a: Number = cap[1].parse().unwrap();
-                let b: Number = cap[2].parse().unwrap();
-                a * b
-            })
-            .sum::<Number>()
-    });
+pub fn part2(input: &Input) -> Result<Answer> {
+    let mut enabled = true;
+    let mut enabled_multiplications_sum: Number = 0;
+
+    for instruction in &input.instructions {
+        match instruction {
+            Instruction::Do => enabled = true,
+            Instruction::Dont => enabled = false,
+            Instruction::Multiply(a, b) if enabled => enabled_multiplications_sum += a * b,
+            Instruction::Multiply(..) => {}
+        }
+    }
 
-    Some(enabled_multiplications_sum.to_string())
+    Ok(enabled_multiplications_sum.into())
 }
 
 /*--------------------------------------------------------------------------------------
   Core
 --------------------------------------------------------------------------------------*/
 
+const DAY: Day = 3;
+
 type Number = u32;
 
-static MUL_REGEX_CELL: OnceLock<Regex> = OnceLock::new();
+static INSTRUCTION_REGEX_CELL: OnceLock<Regex> = OnceLock::new();
 
-fn get_mul_regex() -> &'static Regex {
-    MUL_REGEX_CELL.get_or_init(|| Regex::new(r"mul\((\d+),(\d+)\)").unwrap())
+fn get_instruction_regex() -> &'static Regex {
+    INSTRUCTION_REGEX_CELL
+        .get_or_init(|| Regex::new(r"mul\((\d+),(\d+)\)|do\(\)|don't\(\)").unwrap())
 }
 
-/*-------------------------------------------------------------------------------------------------
-  CLI
--------------------------------------------------------------------------------------------------*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Instruction {
+    Multiply(Number, Number),
+    Do,
+    Dont,
+}
 
-#[derive(clap::Subcommand)]
-#[command(long_about = "Day 3: Mull It Over")]
-pub enum Args {
-    Part1 { input: PathBuf },
-    Part2 { input: PathBuf },
+pub struct Input {
+    instructions: Vec<Instruction>,
 }
 
-pub fn main(args: Args) -> Option<String> {
-    match args {
-        Args::Part1 { input } => part1(&get_input(&input)),
-        Args::Part2 { input } => part2(&get_input(&input)),
+impl Input {
+    pub fn parse(input: &str) -> Result<Self> {
+        let parser = InputParser::new(DAY, input);
+
+        let instructions = get_instruction_regex()
+            .captures_iter(input)
+            .map(|cap| match &cap[0] {
+                "do()" => Ok(Instruction::Do),
+                "don't()" => Ok(Instruction::Dont),
+                _ => Ok(Instruction::Multiply(
+                    parser.parse(cap.get(1).unwrap().as_str())?,
+                    parser.parse(cap.get(2).unwrap().as_str())?,
+                )),
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { instructions })
     }
 }
 
 /*-------------------------------------------------------------------------------------------------
-  Tests
+  CLI
 -------------------------------------------------------------------------------------------------*/
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::get_answer;
+pub struct Day3;
 
-    #[test]
-    fn test_example_solution_part1() {
-        assert_eq!(
-            part1(&get_input("../data/day3/example-part1.txt")),
-            get_answer("../data/day3/example-part1-answer.txt")
-        );
+impl Solution for Day3 {
+    fn day(&self) -> Day {
+        DAY
     }
 
-    #[test]
-    fn test_example_solution_part2() {
-        assert_eq!(
-            part2(&get_input("../data/day3/example-part2.txt")),
-            get_answer("../data/day3/example-part2-answer.txt")
-        );
+    fn title(&self) -> &'static str {
+        "Mull It Over"
     }
 
-    #[test]
-    fn test_part1_solution() {
-        assert_eq!(
-            part1(&get_input("../data/day3/input.txt")),
-            get_answer("../data/day3/input-part1-answer.txt")
-        );
+    fn parse(&self, input: &str) -> Result<ParsedInput> {
+        Ok(ParsedInput::new(Input::parse(input)?))
+    }
+
+    fn part1(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part1(input.get()?)
+    }
+
+    fn part2(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part2(input.get()?)
     }
+}
+
+/*-------------------------------------------------------------------------------------------------
+  Tests
+-------------------------------------------------------------------------------------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
     #[test]
-    fn test_part2_solution() {
+    fn test_parse_input_errors() {
+        let error = Input::parse("mul(2,4)mul(99999999999,1)").err().unwrap();
         assert_eq!(
-            part2(&get_input("../data/day3/input.txt")),
-            get_answer("../data/day3/input-part2-answer.txt")
+            error.to_string(),
+            "Day 3, line 1, column 13: number too large to fit in target type (\"99999999999\")"
         );
     }
 }
@@ -1,275 +1,155 @@
-use crate::get_input;
-use std::path::PathBuf;
+use crate::{
+    Answer, Day, GridIndex, GridMap, GridWindow, InputParser, Parameters, ParsedInput, Solution,
+};
+use anyhow::Result;
 
 /*-------------------------------------------------------------------------------------------------
   Day 4: Ceres Search
 -------------------------------------------------------------------------------------------------*/
 
-pub fn part1(input: &str) -> Option<String> {
-    let word = Word::new("XMAS");
+pub fn part1(input: &Input) -> Result<Answer> {
+    // Reading rows left to right and diagonals down to the right, in each quarter turn of the word
+    // search, covers all eight directions
+    let xmas_count: usize = rotations(&input.word_search)
+        .iter()
+        .map(|word_search| count_in_rows(word_search, XMAS) + count_in_diagonals(word_search, XMAS))
+        .sum();
 
-    let puzzle: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
-    let word_search = WordSearch::new(puzzle);
-
-    let xmas_count = WordSearchIterator::new(&word_search)
-        .filter(|(letter, _)| *letter == 'X')
-        .map(|(_, start)| word_search.find_word(&start, &word))
-        .sum::<i64>();
-
-    Some(xmas_count.to_string())
+    Ok(xmas_count.into())
 }
 
-pub fn part2(input: &str) -> Option<String> {
-    let word = Xmas::new();
-
-    let puzzle: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
-    let word_search = WordSearch::new(puzzle);
+pub fn part2(input: &Input) -> Result<Answer> {
+    // An X-MAS has both Ms on one side, so it matches the pattern in exactly one rotation
+    let x_mas_count: usize = rotations(&input.word_search)
+        .iter()
+        .map(|word_search| count_pattern(word_search, X_MAS))
+        .sum();
 
-    let xmas_count = WordSearchIterator::new(&word_search)
-        .filter(|(letter, _)| *letter == 'A')
-        .filter_map(|(_, start)| word_search.find_x_mas(&start, &word))
-        .sum::<i64>();
-
-    Some(xmas_count.to_string())
+    Ok(x_mas_count.into())
 }
 
 /*--------------------------------------------------------------------------------------
   Core
 --------------------------------------------------------------------------------------*/
 
-/*-----------------------------------------------------------------------------
-  Word Search
------------------------------------------------------------------------------*/
-
-struct WordSearch {
-    puzzle: Vec<Vec<char>>,
-    rows: usize,
-    columns: usize,
-}
-
-impl WordSearch {
-    fn new(puzzle: Vec<Vec<char>>) -> Self {
-        let rows = puzzle.len();
-        let columns = puzzle[0].len();
-        Self {
-            puzzle,
-            rows,
-            columns,
-        }
-    }
-
-    fn get_letter(&self, row: usize, column: usize) -> Option<char> {
-        if row < self.rows && column < self.columns {
-            Some(self.puzzle[row][column])
-        } else {
-            None
-        }
-    }
-
-    fn project(&self, coordinate: &(usize, usize), offset: &(i32, i32)) -> Option<(usize, usize)> {
-        let new_coordinate = (
-            coordinate.0 as i32 + offset.0,
-            coordinate.1 as i32 + offset.1,
-        );
-
-        let new_coordinate = if new_coordinate.0 >= 0 && new_coordinate.1 >= 0 {
-            (new_coordinate.0 as usize, new_coordinate.1 as usize)
-        } else {
-            return None;
-        };
-
-        if new_coordinate.0 < self.rows && new_coordinate.1 < self.columns {
-            Some(new_coordinate)
-        } else {
-            None
-        }
-    }
-
-    fn project_offsets(
-        &self,
-        start: &(usize, usize),
-        offsets: &[(i32, i32)],
-    ) -> Option<Vec<(usize, usize)>> {
-        let mut coordinates = Vec::new();
+const DAY: Day = 4;
 
-        for offset in offsets {
-            coordinates.push(self.project(start, offset)?);
-        }
+type Index = i16;
+type WordSearch = GridMap<Index, char>;
 
-        Some(coordinates)
-    }
-}
-
-/*-----------------------------------------------------------------------------
-  Word Search Iterator
------------------------------------------------------------------------------*/
+const XMAS: &str = "XMAS";
 
-struct WordSearchIterator<'a> {
-    word_search: &'a WordSearch,
-    row: usize,
-    column: usize,
-}
+// Any letter can be in the `.` positions
+const X_MAS: &str = "M.M\n.A.\nS.S";
 
-impl<'a> WordSearchIterator<'a> {
-    fn new(word_search: &'a WordSearch) -> Self {
-        Self {
-            word_search,
-            row: 0,
-            column: 0,
-        }
-    }
+pub struct Input {
+    word_search: WordSearch,
 }
 
-impl Iterator for WordSearchIterator<'_> {
-    type Item = (char, (usize, usize));
-
-    fn next(&mut self) -> Option<Self::Item> {
-        // Get the letter at the current position
-        let letter = self.word_search.get_letter(self.row, self.column)?;
-        let value = (letter, (self.row, self.column));
-
-        // Advance the iterator to the next position
-        let next_column = self.column + 1;
-        (self.row, self.column) = if next_column == self.word_search.columns {
-            (self.row + 1, 0)
-        } else {
-            (self.row, next_column)
-        };
-
-        Some(value)
+impl Input {
+    pub fn parse(input: &str) -> Result<Self> {
+        InputParser::new(DAY, input).rectangular(input)?;
+        Ok(Self {
+            word_search: input.into(),
+        })
     }
 }
 
 /*-----------------------------------------------------------------------------
-  Word
+  Word Search
 -----------------------------------------------------------------------------*/
 
-struct Word {
-    word: String,
-    offsets: [Vec<(i32, i32)>; 8],
+fn rotations(word_search: &WordSearch) -> [WordSearch; 4] {
+    let quarter_turn = word_search.rotate_cw();
+    let half_turn = quarter_turn.rotate_cw();
+    let three_quarter_turn = half_turn.rotate_cw();
+    [
+        word_search.clone(),
+        quarter_turn,
+        half_turn,
+        three_quarter_turn,
+    ]
+}
+
+fn count_in_rows(word_search: &WordSearch, word: &str) -> usize {
+    let word: Vec<char> = word.chars().collect();
+    word_search
+        .rows_iter()
+        .map(|row| {
+            row.windows(word.len())
+                .filter(|letters| *letters == word)
+                .count()
+        })
+        .sum()
+}
+
+fn count_in_diagonals(word_search: &WordSearch, word: &str) -> usize {
+    let size = word.len() as Index;
+    windows(word_search, GridIndex::new(size, size))
+        .filter(|window| {
+            word.chars().enumerate().all(|(offset, letter)| {
+                let offset = offset as Index;
+                window.get(GridIndex::new(offset, offset)) == Some(&letter)
+            })
+        })
+        .count()
+}
+
+fn count_pattern(word_search: &WordSearch, pattern: &str) -> usize {
+    let pattern: WordSearch = pattern.into();
+    let size = GridIndex::new(pattern.rows(), pattern.columns());
+    let letters: Vec<(GridIndex<Index>, char)> = pattern
+        .enumerate()
+        .filter(|(_, &letter)| letter != '.')
+        .map(|(index, &letter)| (index, letter))
+        .collect();
+
+    windows(word_search, size)
+        .filter(|window| {
+            letters
+                .iter()
+                .all(|(index, letter)| window.get(*index) == Some(letter))
+        })
+        .count()
+}
+
+// Every window of the given size that fits within the word search
+fn windows(
+    word_search: &WordSearch,
+    size: GridIndex<Index>,
+) -> impl Iterator<Item = GridWindow<'_, Index, char>> {
+    let last = GridIndex::new(word_search.rows(), word_search.columns()) - size;
+    (0..=last.row).flat_map(move |row| {
+        (0..=last.column)
+            .filter_map(move |column| word_search.window(GridIndex::new(row, column), size))
+    })
 }
 
-impl Word {
-    fn new(word: &str) -> Self {
-        let word_length = word.len();
-
-        let mut offsets: [Vec<(i32, i32)>; 8] = [
-            Vec::with_capacity(word_length),
-            Vec::with_capacity(word_length),
-            Vec::with_capacity(word_length),
-            Vec::with_capacity(word_length),
-            Vec::with_capacity(word_length),
-            Vec::with_capacity(word_length),
-            Vec::with_capacity(word_length),
-            Vec::with_capacity(word_length),
-        ];
-
-        for index in 0..word_length {
-            let index = index as i32;
-            offsets[0].push((-index, -index)); // North West
-            offsets[1].push((-index, 0)); // North
-            offsets[2].push((-index, index)); // North East
-            offsets[3].push((0, index)); // East
-            offsets[4].push((index, index)); // South East
-            offsets[5].push((index, 0)); // South
-            offsets[6].push((index, -index)); // South West
-            offsets[7].push((0, -index)); // West
-        }
-
-        Self {
-            word: word.to_string(),
-            offsets,
-        }
-    }
-}
+/*-------------------------------------------------------------------------------------------------
+  CLI
+-------------------------------------------------------------------------------------------------*/
 
-/*-----------------------------------------------------------------------------
-  Word Search Methods
------------------------------------------------------------------------------*/
+pub struct Day4;
 
-impl WordSearch {
-    fn find_word(&self, start: &(usize, usize), word: &Word) -> i64 {
-        word.offsets
-            .iter()
-            .filter_map(|offsets| self.project_offsets(start, offsets))
-            .map(|coordinates| self.check_word(&coordinates, &word.word))
-            .filter(|found| *found)
-            .count() as i64
+impl Solution for Day4 {
+    fn day(&self) -> Day {
+        DAY
     }
 
-    fn check_word(&self, coordinates: &[(usize, usize)], word: &str) -> bool {
-        assert!(coordinates.len() == word.len());
-        coordinates
-            .iter()
-            .enumerate()
-            .all(|(index, (row, column))| {
-                self.get_letter(*row, *column) == Some(word.chars().nth(index).unwrap())
-            })
+    fn title(&self) -> &'static str {
+        "Ceres Search"
     }
-}
-
-/*-----------------------------------------------------------------------------
-  Xmas Word
------------------------------------------------------------------------------*/
-
-struct Xmas {
-    offsets: [(i32, i32); 5],
-}
 
-impl Xmas {
-    fn new() -> Self {
-        Self {
-            offsets: [
-                (-1, -1), // North West
-                (-1, 1),  // North East
-                (0, 0),   // Center
-                (1, -1),  // South West
-                (1, 1),   // South East
-            ],
-        }
+    fn parse(&self, input: &str) -> Result<ParsedInput> {
+        Ok(ParsedInput::new(Input::parse(input)?))
     }
-}
-
-/*-----------------------------------------------------------------------------
-  Word Search Methods
------------------------------------------------------------------------------*/
-
-impl WordSearch {
-    fn find_x_mas(&self, start: &(usize, usize), xmas: &Xmas) -> Option<i64> {
-        let coordinates = self.project_offsets(start, &xmas.offsets)?;
-        let letters: [char; 5] = [
-            self.get_letter(coordinates[0].0, coordinates[0].1)?,
-            self.get_letter(coordinates[1].0, coordinates[1].1)?,
-            self.get_letter(coordinates[2].0, coordinates[2].1)?,
-            self.get_letter(coordinates[3].0, coordinates[3].1)?,
-            self.get_letter(coordinates[4].0, coordinates[4].1)?,
-        ];
 
-        match letters {
-            ['M', 'M', 'A', 'S', 'S'] => Some(1),
-            ['M', 'S', 'A', 'M', 'S'] => Some(1),
-            ['S', 'M', 'A', 'S', 'M'] => Some(1),
-            ['S', 'S', 'A', 'M', 'M'] => Some(1),
-            _ => None,
-        }
+    fn part1(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part1(input.get()?)
     }
-}
 
-/*-------------------------------------------------------------------------------------------------
-  CLI
--------------------------------------------------------------------------------------------------*/
-
-#[derive(clap::Subcommand)]
-#[command(long_about = "Day 4: Ceres Search")]
-pub enum Args {
-    Part1 { input: PathBuf },
-    Part2 { input: PathBuf },
-}
-
-pub fn main(args: Args) -> Option<String> {
-    match args {
-        Args::Part1 { input } => part1(&get_input(&input)),
-        Args::Part2 { input } => part2(&get_input(&input)),
+    fn part2(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part2(input.get()?)
     }
 }
 
@@ -280,37 +160,13 @@ pub fn main(args: Args) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::get_answer;
-
-    #[test]
-    fn test_example_solution_part1() {
-        assert_eq!(
-            part1(&get_input("../data/day4/example.txt")),
-            get_answer("../data/day4/example-part1-answer.txt")
-        );
-    }
-
-    #[test]
-    fn test_example_solution_part2() {
-        assert_eq!(
-            part2(&get_input("../data/day4/example.txt")),
-            get_answer("../data/day4/example-part2-answer.txt")
-        );
-    }
-
-    #[test]
-    fn test_part1_solution() {
-        assert_eq!(
-            part1(&get_input("../data/day4/input.txt")),
-            get_answer("../data/day4/input-part1-answer.txt")
-        );
-    }
 
     #[test]
-    fn test_part2_solution() {
+    fn test_parse_input_errors() {
+        let error = Input::parse("XMAS\nXMA\n").err().unwrap();
         assert_eq!(
-            part2(&get_input("../data/day4/input.txt")),
-            get_answer("../data/day4/input-part2-answer.txt")
+            error.to_string(),
+            "Day 4, line 2, column 1: Expected 4 columns, found 3 (\"XMA\")"
         );
     }
 }
@@ -1,68 +1,87 @@
-use crate::get_input;
+use crate::{Answer, Day, InputParser, Parameters, ParsedInput, Solution};
+use anyhow::Result;
 use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet};
-use std::path::PathBuf;
 
 /*-------------------------------------------------------------------------------------------------
   Day 5: Print Queue
 -------------------------------------------------------------------------------------------------*/
 
-pub fn part1(input: &str) -> Option<String> {
-    let (ordering_rules, updates) = parse_input(input);
+pub fn part1(input: &Input) -> Result<Answer> {
+    let Input {
+        ordering_rules,
+        updates,
+    } = input;
 
     let middle_page_sum = updates
         .iter()
-        .filter(|update| validate_page_order(&ordering_rules, update))
+        .filter(|update| validate_page_order(ordering_rules, update))
         .map(|update| update[update.len() / 2] as i64)
         .sum::<i64>();
 
-    Some(middle_page_sum.to_string())
+    Ok(middle_page_sum.into())
 }
 
-pub fn part2(input: &str) -> Option<String> {
-    let (ordering_rules, updates) = parse_input(input);
+pub fn part2(input: &Input) -> Result<Answer> {
+    let Input {
+        ordering_rules,
+        updates,
+    } = input;
 
     let middle_page_sum = updates
         .iter()
-        .filter_map(|update| corrected_update(&ordering_rules, update))
+        .filter_map(|update| corrected_update(ordering_rules, update))
         .map(|update| update[update.len() / 2] as i64)
         .sum::<i64>();
 
-    Some(middle_page_sum.to_string())
+    Ok(middle_page_sum.into())
 }
 
 /*--------------------------------------------------------------------------------------
   Core
 --------------------------------------------------------------------------------------*/
 
+const DAY: Day = 5;
+
 type Updates = Vec<u8>;
 
-fn parse_input(input: &str) -> (OrderingRules, Vec<Updates>) {
-    let mut lines = input.lines();
+pub struct Input {
+    ordering_rules: OrderingRules,
+    updates: Vec<Updates>,
+}
+
+impl Input {
+    pub fn parse(input: &str) -> Result<Self> {
+        let parser = InputParser::new(DAY, input);
+        let mut lines = input.lines();
+
+        let mut ordering_rules = OrderingRules::new();
+        for line in lines.by_ref() {
+            if line.is_empty() {
+                break;
+            }
 
-    let mut ordering_rules = OrderingRules::new();
-    for line in lines.by_ref() {
-        if line.is_empty() {
-            break;
+            let mut pages = line.split('|');
+            ordering_rules.insert(
+                parser.parse(parser.required(pages.next(), line)?)?,
+                parser.parse(parser.required(pages.next(), line)?)?,
+            );
         }
 
-        let mut pages = line.split('|');
-        ordering_rules.insert(
-            pages.next().unwrap().parse::<u8>().unwrap(),
-            pages.next().unwrap().parse::<u8>().unwrap(),
-        );
-    }
+        let mut updates: Vec<Updates> = Vec::new();
+        for line in lines.by_ref() {
+            let pages = line
+                .split(',')
+                .map(|page| parser.parse(page))
+                .collect::<Result<Updates, _>>()?;
+            updates.push(pages);
+        }
 
-    let mut updates: Vec<Updates> = Vec::new();
-    for line in lines.by_ref() {
-        let pages: Vec<_> = line
-            .split(',')
-            .map(|page| page.parse::<u8>().unwrap())
-            .collect();
-        updates.push(pages);
+        Ok(Self {
+            ordering_rules,
+            updates,
+        })
     }
-
-    (ordering_rules, updates)
 }
 
 fn validate_page_order(ordering_rules: &OrderingRules, update: &Updates) -> bool {
@@ -119,17 +138,27 @@ fn sort_pages(pages: &Updates, ordering_rules: &OrderingRules) -> Updates {
   CLI
 -------------------------------------------------------------------------------------------------*/
 
-#[derive(clap::Subcommand)]
-#[command(long_about = "Day 5: Print Queue")]
-pub enum Args {
-    Part1 { input: PathBuf },
-    Part2 { input: PathBuf },
-}
+pub struct Day5;
+
+impl Solution for Day5 {
+    fn day(&self) -> Day {
+        DAY
+    }
+
+    fn title(&self) -> &'static str {
+        "Print Queue"
+    }
+
+    fn parse(&self, input: &str) -> Result<ParsedInput> {
+        Ok(ParsedInput::new(Input::parse(input)?))
+    }
+
+    fn part1(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part1(input.get()?)
+    }
 
-pub fn main(args: Args) -> Option<String> {
-    match args {
-        Args::Part1 { input } => part1(&get_input(&input)),
-        Args::Part2 { input } => part2(&get_input(&input)),
+    fn part2(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part2(input.get()?)
     }
 }
 
@@ -140,37 +169,19 @@ pub fn main(args: Args) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::get_answer;
 
     #[test]
-    fn test_example_solution_part1() {
+    fn test_parse_input_errors() {
+        let error = Input::parse("47|53\n97\n\n75,47\n").err().unwrap();
         assert_eq!(
-            part1(&get_input("../data/day5/example.txt")),
-            get_answer("../data/day5/example-part1-answer.txt")
+            error.to_string(),
+            "Day 5, line 2, column 1: Missing value (\"97\")"
         );
-    }
 
-    #[test]
-    fn test_example_solution_part2() {
-        assert_eq!(
-            part2(&get_input("../data/day5/example.txt")),
-            get_answer("../data/day5/example-part2-answer.txt")
-        );
-    }
-
-    #[test]
-    fn test_part1_solution() {
-        assert_eq!(
-            part1(&get_input("../data/day5/input.txt")),
-            get_answer("../data/day5/input-part1-answer.txt")
-        );
-    }
-
-    #[test]
-    fn test_part2_solution() {
+        let error = Input::parse("47|53\n\n75,x\n").err().unwrap();
         assert_eq!(
-            part2(&get_input("../data/day5/input.txt")),
-            get_answer("../data/day5/input-part2-answer.txt")
+            error.to_string(),
+            "Day 5, line 3, column 4: invalid digit found in string (\"x\")"
         );
     }
 }
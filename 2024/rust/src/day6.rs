@@ -1,24 +1,28 @@
-use crate::{get_input, GridDirection, GridIndex, GridMap};
+use crate::{
+    Answer, Day, GridDirection, GridIndex, GridMap, InputParser, Parameters, ParsedInput, Solution,
+};
+use anyhow::Result;
 use std::collections::HashSet;
-use std::path::PathBuf;
 
 /*-------------------------------------------------------------------------------------------------
   Day 6: Guard Gallivant
 -------------------------------------------------------------------------------------------------*/
 
-pub fn part1(input: &str) -> Option<String> {
-    let (map, mut guard) = parse_input(input);
+pub fn part1(input: &Input) -> Result<Answer> {
+    let map = &input.map;
+    let mut guard = input.guard.clone();
 
-    while guard.next(&map) != Action::Exit {}
+    while guard.next(map) != Action::Exit {}
 
     let visited_positions: HashSet<Position> =
         guard.route.iter().map(|(position, _)| *position).collect();
 
-    Some(visited_positions.len().to_string())
+    Ok(visited_positions.len().into())
 }
 
-pub fn part2(input: &str) -> Option<String> {
-    let (mut map, mut guard) = parse_input(input);
+pub fn part2(input: &Input) -> Result<Answer> {
+    let mut map = input.map.clone();
+    let mut guard = input.guard.clone();
 
     let mut checked_positions = HashSet::new();
     let mut loop_obstruction_positions = HashSet::new();
@@ -56,22 +60,36 @@ pub fn part2(input: &str) -> Option<String> {
         }
     }
 
-    Some(loop_obstruction_positions.len().to_string())
+    Ok(loop_obstruction_positions.len().into())
 }
 
 /*--------------------------------------------------------------------------------------
   Core
 --------------------------------------------------------------------------------------*/
 
+const DAY: Day = 6;
+
 type Index = i16;
 type Map = GridMap<Index, char>;
 type Position = GridIndex<Index>;
 
-fn parse_input(input: &str) -> (GridMap<Index, char>, Guard) {
-    let map: GridMap<Index, char> = input.into();
-    let start_position = map.find(|c| c == &'^').unwrap();
-    let guard = Guard::new(start_position, GridDirection::Up);
-    (map, guard)
+pub struct Input {
+    map: Map,
+    guard: Guard,
+}
+
+impl Input {
+    pub fn parse(input: &str) -> Result<Self> {
+        let parser = InputParser::new(DAY, input);
+        parser.rectangular(input)?;
+
+        let map: GridMap<Index, char> = input.into();
+        let start_position = map
+            .find(|c| c == &'^')
+            .ok_or_else(|| parser.error_at_end("Missing guard start position '^'"))?;
+        let guard = Guard::new(start_position, GridDirection::Up);
+        Ok(Self { map, guard })
+    }
 }
 
 /*-----------------------------------------------------------------------------
@@ -150,17 +168,27 @@ enum Action {
   CLI
 -------------------------------------------------------------------------------------------------*/
 
-#[derive(clap::Subcommand)]
-#[command(long_about = "Day 6: Guard Gallivant")]
-pub enum Args {
-    Part1 { input: PathBuf },
-    Part2 { input: PathBuf },
-}
+pub struct Day6;
+
+impl Solution for Day6 {
+    fn day(&self) -> Day {
+        DAY
+    }
+
+    fn title(&self) -> &'static str {
+        "Guard Gallivant"
+    }
+
+    fn parse(&self, input: &str) -> Result<ParsedInput> {
+        Ok(ParsedInput::new(Input::parse(input)?))
+    }
+
+    fn part1(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part1(input.get()?)
+    }
 
-pub fn main(args: Args) -> Option<String> {
-    match args {
-        Args::Part1 { input } => part1(&get_input(&input)),
-        Args::Part2 { input } => part2(&get_input(&input)),
+    fn part2(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part2(input.get()?)
     }
 }
 
@@ -171,38 +199,19 @@ pub fn main(args: Args) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::get_answer;
 
     #[test]
-    fn test_example_solution_part1() {
+    fn test_parse_input_errors() {
+        let error = Input::parse("....\n.#..\n").err().unwrap();
         assert_eq!(
-            part1(&get_input("../data/day6/example.txt")),
-            get_answer("../data/day6/example-part1-answer.txt")
+            error.to_string(),
+            "Day 6, line 3, column 1: Missing guard start position '^'"
         );
-    }
-
-    #[test]
-    fn test_example_solution_part2() {
-        assert_eq!(
-            part2(&get_input("../data/day6/example.txt")),
-            get_answer("../data/day6/example-part2-answer.txt")
-        );
-    }
 
-    #[test]
-    fn test_part1_solution() {
-        assert_eq!(
-            part1(&get_input("../data/day6/input.txt")),
-            get_answer("../data/day6/input-part1-answer.txt")
-        );
-    }
-
-    #[test]
-    #[cfg_attr(not(feature = "slow_tests"), ignore)]
-    fn test_part2_solution() {
+        let error = Input::parse("...\n..\n").err().unwrap();
         assert_eq!(
-            part2(&get_input("../data/day6/input.txt")),
-            get_answer("../data/day6/input-part2-answer.txt")
+            error.to_string(),
+            "Day 6, line 2, column 1: Expected 3 columns, found 2 (\"..\")"
         );
     }
 }
@@ -1,26 +1,24 @@
-use crate::get_input;
-use std::path::PathBuf;
+use crate::{Answer, Day, InputParser, Parameters, ParsedInput, Solution};
+use anyhow::Result;
 
 /*-------------------------------------------------------------------------------------------------
   Day 7: Bridge Repair
 -------------------------------------------------------------------------------------------------*/
 
-pub fn part1(input: &str) -> Option<String> {
-    let calibration_equations = parse_input(input);
-
-    let total_calibration_results = calibration_equations
+pub fn part1(input: &Input) -> Result<Answer> {
+    let total_calibration_results = input
+        .calibration_equations
         .iter()
         .filter(|(result, terms)| validate_equation(*result, terms, None, &[add, multiply]))
         .map(|(result, _)| result)
         .sum::<i64>();
 
-    Some(total_calibration_results.to_string())
+    Ok(total_calibration_results.into())
 }
 
-pub fn part2(input: &str) -> Option<String> {
-    let calibration_equations = parse_input(input);
-
-    let total_calibration_result = calibration_equations
+pub fn part2(input: &Input) -> Result<Answer> {
+    let total_calibration_result = input
+        .calibration_equations
         .iter()
         .filter(|(result, terms)| {
             validate_equation(*result, terms, None, &[add, multiply, concatenate])
@@ -28,23 +26,45 @@ pub fn part2(input: &str) -> Option<String> {
         .map(|(result, _)| result)
         .sum::<i64>();
 
-    Some(total_calibration_result.to_string())
+    Ok(total_calibration_result.into())
 }
 
 /*--------------------------------------------------------------------------------------
   Core
 --------------------------------------------------------------------------------------*/
 
-fn parse_input(input: &str) -> Vec<(i64, Vec<i64>)> {
-    input
-        .lines()
-        .map(|line| {
-            let mut split = line.split(&[':', ' ']);
-            let result: i64 = split.next().unwrap().parse().unwrap();
-            let terms: Vec<i64> = split.skip(1).map(|x| x.parse().unwrap()).collect();
-            (result, terms)
+const DAY: Day = 7;
+
+pub struct Input {
+    calibration_equations: Vec<(i64, Vec<i64>)>,
+}
+
+impl Input {
+    pub fn parse(input: &str) -> Result<Self> {
+        let parser = InputParser::new(DAY, input);
+
+        let calibration_equations = input
+            .lines()
+            .map(|line| {
+                let mut split = line.split(&[':', ' ']);
+                let result: i64 = parser.parse(parser.required(split.next(), line)?)?;
+                let terms = split
+                    .skip(1)
+                    .map(|x| parser.parse(x))
+                    .collect::<Result<Vec<i64>, _>>()?;
+
+                if terms.is_empty() {
+                    return Err(parser.error(line, "Missing equation terms").into());
+                }
+
+                Ok((result, terms))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            calibration_equations,
         })
-        .collect()
+    }
 }
 
 fn validate_equation(
@@ -87,17 +107,27 @@ fn concatenate(acc: Option<i64>, term: i64) -> i64 {
   CLI
 -------------------------------------------------------------------------------------------------*/
 
-#[derive(clap::Subcommand)]
-#[command(long_about = "Day 7: Bridge Repair")]
-pub enum Args {
-    Part1 { input: PathBuf },
-    Part2 { input: PathBuf },
-}
+pub struct Day7;
 
-pub fn main(args: Args) -> Option<String> {
-    match args {
-        Args::Part1 { input } => part1(&get_input(&input)),
-        Args::Part2 { input } => part2(&get_input(&input)),
+impl Solution for Day7 {
+    fn day(&self) -> Day {
+        DAY
+    }
+
+    fn title(&self) -> &'static str {
+        "Bridge Repair"
+    }
+
+    fn parse(&self, input: &str) -> Result<ParsedInput> {
+        Ok(ParsedInput::new(Input::parse(input)?))
+    }
+
+    fn part1(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part1(input.get()?)
+    }
+
+    fn part2(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part2(input.get()?)
     }
 }
 
@@ -108,37 +138,19 @@ pub fn main(args: Args) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::get_answer;
 
     #[test]
-    fn test_example_solution_part1() {
+    fn test_parse_input_errors() {
+        let error = Input::parse("190: 10 19\n3267\n").err().unwrap();
         assert_eq!(
-            part1(&get_input("../data/day7/example.txt")),
-            get_answer("../data/day7/example-part1-answer.txt")
+            error.to_string(),
+            "Day 7, line 2, column 1: Missing equation terms (\"3267\")"
         );
-    }
-
-    #[test]
-    fn test_example_solution_part2() {
-        assert_eq!(
-            part2(&get_input("../data/day7/example.txt")),
-            get_answer("../data/day7/example-part2-answer.txt")
-        );
-    }
 
-    #[test]
-    fn test_part1_solution() {
-        assert_eq!(
-            part1(&get_input("../data/day7/input.txt")),
-            get_answer("../data/day7/input-part1-answer.txt")
-        );
-    }
-
-    #[test]
-    fn test_part2_solution() {
+        let error = Input::parse("190: 10 x\n").err().unwrap();
         assert_eq!(
-            part2(&get_input("../data/day7/input.txt")),
-            get_answer("../data/day7/input-part2-answer.txt")
+            error.to_string(),
+            "Day 7, line 1, column 9: invalid digit found in string (\"x\")"
         );
     }
 }
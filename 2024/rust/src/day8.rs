@@ -1,68 +1,81 @@
-use crate::get_input;
+use crate::{Answer, Day, InputParser, Parameters, ParsedInput, Solution};
+use anyhow::Result;
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
-use std::path::PathBuf;
 
 /*-------------------------------------------------------------------------------------------------
   Day 8: Resonant Collinearity
 -------------------------------------------------------------------------------------------------*/
 
-pub fn part1(input: &str) -> Option<String> {
-    let map = parse_input(input);
+pub fn part1(input: &Input) -> Result<Answer> {
+    let map = &input.map;
 
     let anti_nodes: HashSet<Coordinate> = get_antenna_pairs(&map.antennas)
         .iter()
         .flat_map(project_anti_nodes)
-        .filter(|coordinate| filter_off_map_coordinates(&map, coordinate))
+        .filter(|coordinate| filter_off_map_coordinates(map, coordinate))
         .collect();
 
-    Some(anti_nodes.len().to_string())
+    Ok(anti_nodes.len().into())
 }
 
-pub fn part2(input: &str) -> Option<String> {
-    let map = parse_input(input);
+pub fn part2(input: &Input) -> Result<Answer> {
+    let map = &input.map;
 
     let anti_nodes: HashSet<Coordinate> = get_antenna_pairs(&map.antennas)
         .iter()
-        .flat_map(|antennas| project_resonant_anti_nodes(antennas, &map))
-        .filter(|coordinate| filter_off_map_coordinates(&map, coordinate))
+        .flat_map(|antennas| project_resonant_anti_nodes(antennas, map))
+        .filter(|coordinate| filter_off_map_coordinates(map, coordinate))
         .collect();
 
-    Some(anti_nodes.len().to_string())
+    Ok(anti_nodes.len().into())
 }
 
 /*--------------------------------------------------------------------------------------
   Core
 --------------------------------------------------------------------------------------*/
 
+const DAY: Day = 8;
+
 type Frequency = char;
 type Coordinate = (i32, i32);
 type Antennas = HashMap<Frequency, Vec<Coordinate>>;
 
 /*-----------------------------------------------------------------------------
-  Parse Input File
+  Input
 -----------------------------------------------------------------------------*/
 
-fn parse_input(input: &str) -> Map {
-    let rows = input.lines().count() as i32;
-    let columns = input.lines().next().unwrap().chars().count() as i32;
-
-    let mut antennas = Antennas::new();
-    for (row, line) in input.lines().enumerate() {
-        for (column, frequency) in line.chars().enumerate() {
-            if frequency != '.' {
-                antennas
-                    .entry(frequency)
-                    .or_default()
-                    .push((row as i32, column as i32));
+pub struct Input {
+    map: Map,
+}
+
+impl Input {
+    pub fn parse(input: &str) -> Result<Self> {
+        let parser = InputParser::new(DAY, input);
+        parser.rectangular(input)?;
+
+        let rows = input.lines().count() as i32;
+        let columns = parser.first_line()?.chars().count() as i32;
+
+        let mut antennas = Antennas::new();
+        for (row, line) in input.lines().enumerate() {
+            for (column, frequency) in line.chars().enumerate() {
+                if frequency != '.' {
+                    antennas
+                        .entry(frequency)
+                        .or_default()
+                        .push((row as i32, column as i32));
+                }
             }
         }
-    }
 
-    Map {
-        rows,
-        columns,
-        antennas,
+        Ok(Self {
+            map: Map {
+                rows,
+                columns,
+                antennas,
+            },
+        })
     }
 }
 
@@ -145,17 +158,27 @@ fn filter_off_map_coordinates(map: &Map, coordinate: &Coordinate) -> bool {
   CLI
 -------------------------------------------------------------------------------------------------*/
 
-#[derive(clap::Subcommand)]
-#[command(long_about = "Day 8: Resonant Collinearity")]
-pub enum Args {
-    Part1 { input: PathBuf },
-    Part2 { input: PathBuf },
-}
+pub struct Day8;
+
+impl Solution for Day8 {
+    fn day(&self) -> Day {
+        DAY
+    }
 
-pub fn main(args: Args) -> Option<String> {
-    match args {
-        Args::Part1 { input } => part1(&get_input(&input)),
-        Args::Part2 { input } => part2(&get_input(&input)),
+    fn title(&self) -> &'static str {
+        "Resonant Collinearity"
+    }
+
+    fn parse(&self, input: &str) -> Result<ParsedInput> {
+        Ok(ParsedInput::new(Input::parse(input)?))
+    }
+
+    fn part1(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part1(input.get()?)
+    }
+
+    fn part2(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part2(input.get()?)
     }
 }
 
@@ -166,37 +189,13 @@ pub fn main(args: Args) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::get_answer;
-
-    #[test]
-    fn test_example_solution_part1() {
-        assert_eq!(
-            part1(&get_input("../data/day8/example.txt")),
-            get_answer("../data/day8/example-part1-answer.txt")
-        );
-    }
-
-    #[test]
-    fn test_example_solution_part2() {
-        assert_eq!(
-            part2(&get_input("../data/day8/example.txt")),
-            get_answer("../data/day8/example-part2-answer.txt")
-        );
-    }
-
-    #[test]
-    fn test_part1_solution() {
-        assert_eq!(
-            part1(&get_input("../data/day8/input.txt")),
-            get_answer("../data/day8/input-part1-answer.txt")
-        );
-    }
 
     #[test]
-    fn test_part2_solution() {
+    fn test_parse_input_errors() {
+        let error = Input::parse("..a.\n..\n").err().unwrap();
         assert_eq!(
-            part2(&get_input("../data/day8/input.txt")),
-            get_answer("../data/day8/input-part2-answer.txt")
+            error.to_string(),
+            "Day 8, line 2, column 1: Expected 4 columns, found 2 (\"..\")"
         );
     }
 }
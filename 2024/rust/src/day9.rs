@@ -1,32 +1,52 @@
-use crate::get_input;
+use crate::{Answer, Day, InputParser, Parameters, ParsedInput, Solution};
+use anyhow::Result;
 use std::collections::VecDeque;
-use std::path::PathBuf;
 
 /*-------------------------------------------------------------------------------------------------
   Day 9: Disk Fragmenter
 -------------------------------------------------------------------------------------------------*/
 
-pub fn part1(input: &str) -> Option<String> {
-    let mut disk = parse_input(input);
+pub fn part1(input: &Input) -> Result<Answer> {
+    let mut disk = input.disk.clone();
     disk.compact_blocks();
 
-    Some(disk.checksum().to_string())
+    Ok(disk.checksum().into())
 }
 
-pub fn part2(input: &str) -> Option<String> {
-    let mut disk = parse_input(input);
+pub fn part2(input: &Input) -> Result<Answer> {
+    let mut disk = input.disk.clone();
     disk.compact_files();
 
-    Some(disk.checksum().to_string())
+    Ok(disk.checksum().into())
 }
 
 /*--------------------------------------------------------------------------------------
   Core
 --------------------------------------------------------------------------------------*/
 
-fn parse_input(input: &str) -> Disk {
-    let allocation_string = input.lines().next().unwrap();
-    Disk::new(allocation_string)
+const DAY: Day = 9;
+
+pub struct Input {
+    disk: Disk,
+}
+
+impl Input {
+    pub fn parse(input: &str) -> Result<Self> {
+        let parser = InputParser::new(DAY, input);
+        let allocation_string = parser.first_line()?;
+
+        let dense_format = allocation_string
+            .char_indices()
+            .map(|(index, c)| match c.to_digit(10) {
+                Some(digit) => Ok(digit as BlockCount),
+                None => Err(parser.char_error(allocation_string, index, "Expected a digit")),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            disk: Disk::new(&dense_format),
+        })
+    }
 }
 
 /*-----------------------------------------------------------------------------
@@ -51,6 +71,7 @@ struct FreeSpaceAllocation {
     length: BlockCount,
 }
 
+#[derive(Clone)]
 struct Disk {
     files: Vec<FileAllocation>,
     free_space: FreeSpaceAllocator,
@@ -58,7 +79,7 @@ struct Disk {
 }
 
 impl Disk {
-    fn new(dense_format: &str) -> Self {
+    fn new(dense_format: &[BlockCount]) -> Self {
         let allocation_count = dense_format.len() / 2;
 
         let mut files = Vec::with_capacity(allocation_count);
@@ -67,9 +88,7 @@ impl Disk {
         let mut next_file_id: FileId = 0;
         let mut next_block_index: usize = 0;
 
-        for (dense_index, allocation_length) in dense_format.chars().enumerate() {
-            let allocation_length = allocation_length.to_digit(10).unwrap() as BlockCount;
-
+        for (dense_index, &allocation_length) in dense_format.iter().enumerate() {
             if dense_index % 2 == 0 {
                 files.push(FileAllocation {
                     id: next_file_id,
@@ -202,6 +221,7 @@ impl Disk {
   Free Space Allocator
 -----------------------------------------------------------------------------*/
 
+#[derive(Clone)]
 struct FreeSpaceAllocator {
     queues: [VecDeque<FreeSpaceAllocation>; 10],
 }
@@ -283,17 +303,27 @@ impl FreeSpaceAllocator {
   CLI
 -------------------------------------------------------------------------------------------------*/
 
-#[derive(clap::Subcommand)]
-#[command(long_about = "Day 9: Disk Fragmenter")]
-pub enum Args {
-    Part1 { input: PathBuf },
-    Part2 { input: PathBuf },
-}
+pub struct Day9;
+
+impl Solution for Day9 {
+    fn day(&self) -> Day {
+        DAY
+    }
+
+    fn title(&self) -> &'static str {
+        "Disk Fragmenter"
+    }
+
+    fn parse(&self, input: &str) -> Result<ParsedInput> {
+        Ok(ParsedInput::new(Input::parse(input)?))
+    }
 
-pub fn main(args: Args) -> Option<String> {
-    match args {
-        Args::Part1 { input } => part1(&get_input(&input)),
-        Args::Part2 { input } => part2(&get_input(&input)),
+    fn part1(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part1(input.get()?)
+    }
+
+    fn part2(&self, input: &ParsedInput, _: &Parameters) -> Result<Answer> {
+        part2(input.get()?)
     }
 }
 
@@ -304,37 +334,16 @@ pub fn main(args: Args) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::get_answer;
-
-    #[test]
-    fn test_example_solution_part1() {
-        assert_eq!(
-            part1(&get_input("../data/day9/example.txt")),
-            get_answer("../data/day9/example-part1-answer.txt")
-        );
-    }
 
     #[test]
-    fn test_part1_solution() {
+    fn test_parse_input_errors() {
+        let error = Input::parse("2333x\n").err().unwrap();
         assert_eq!(
-            part1(&get_input("../data/day9/input.txt")),
-            get_answer("../data/day9/input-part1-answer.txt")
+            error.to_string(),
+            "Day 9, line 1, column 5: Expected a digit (\"x\")"
         );
-    }
 
-    #[test]
-    fn test_example_solution_part2() {
-        assert_eq!(
-            part2(&get_input("../data/day9/example.txt")),
-            get_answer("../data/day9/example-part2-answer.txt")
-        );
-    }
-
-    #[test]
-    fn test_part2_solution() {
-        assert_eq!(
-            part2(&get_input("../data/day9/input.txt")),
-            get_answer("../data/day9/input-part2-answer.txt")
-        );
+        let error = Input::parse("").err().unwrap();
+        assert_eq!(error.to_string(), "Day 9, line 1, column 1: Input is empty");
     }
 }
@@ -8,11 +8,39 @@
 
 mod shared;
 
-pub use shared::answers::get_answer;
-pub use shared::grid_directions::{AnyDirection, GridDirection};
+pub use shared::allocations::{
+    count_allocations, format_bytes, AllocationStats, CountingAllocator,
+};
+pub use shared::answers::{get_answer, Answer};
+pub use shared::bench_report::{
+    compare_baselines, comparison_table, criterion_dir, Change, Comparison, Estimate,
+};
+pub use shared::budgets::{Budget, Budgets, BUDGETS_FILE};
+pub use shared::cli::{solution_command, solution_commands, SolutionArgs};
+pub use shared::client::{
+    Fetched, PuzzleClient, Submission, BASE_URL_ENV, DEFAULT_BASE_URL, SESSION_ENV,
+};
+pub use shared::data_dir::{data_dir, data_parameters, day_file, locate_data_file, DATA_DIR_ENV};
+pub use shared::examples::{
+    example_files, write_data_files, CodeBlock, DataFile, ExampleAnswer, PuzzlePage,
+};
+pub use shared::grid_directions::{AnyDirection, GridDirection, OctalDirection};
 pub use shared::grid_index::GridIndex;
-pub use shared::grid_map::GridMap;
-pub use shared::inputs::get_input;
+pub use shared::grid_map::{Components, GridMap, GridWindow};
+pub use shared::grid_region::GridRegion;
+pub use shared::inputs::{get_input, InputSource};
+pub use shared::output::{csv_records, json_records, OutputFormat};
+pub use shared::parsing::{InputParser, ParseError};
+pub use shared::profile::{enable_profiling, profile_spans, profile_tree, span, Span, SpanTiming};
+pub use shared::runner::{
+    answer_path, record_answer, results_table, run, run_all, run_parts, Recorded, RunResult, Status,
+};
+pub use shared::scaffold::new_day;
+pub use shared::search::{astar, bfs, dijkstra, Predecessors, SearchResult};
+pub use shared::solution::{
+    Day, Parameter, ParameterKind, Parameters, ParsedInput, Part, Solution, SolutionError,
+};
+pub use shared::watch::{check_day, check_summary, day_cases, Watcher};
 
 /*--------------------------------------------------------------------------------------
   Solution Modules
@@ -43,3 +71,64 @@ pub mod day6;
 pub mod day7;
 pub mod day8;
 pub mod day9;
+
+/*--------------------------------------------------------------------------------------
+  Solution Registry
+--------------------------------------------------------------------------------------*/
+
+pub static SOLUTIONS: &[&dyn Solution] = &[
+    &day1::Day1,
+    &day2::Day2,
+    &day3::Day3,
+    &day4::Day4,
+    &day5::Day5,
+    &day6::Day6,
+    &day7::Day7,
+    &day8::Day8,
+    &day9::Day9,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+    &day17::Day17,
+    &day18::Day18,
+    &day19::Day19,
+    &day20::Day20,
+    &day21::Day21,
+    &day22::Day22,
+    &day23::Day23,
+    &day24::Day24,
+    &day25::Day25,
+];
+
+pub fn get_solution(day: Day) -> Option<&'static dyn Solution> {
+    SOLUTIONS
+        .iter()
+        .find(|solution| solution.day() == day)
+        .copied()
+}
+
+/*-------------------------------------------------------------------------------------------------
+  Tests
+-------------------------------------------------------------------------------------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solutions_are_registered_in_day_order() {
+        let days: Vec<Day> = SOLUTIONS.iter().map(|solution| solution.day()).collect();
+        let expected: Vec<Day> = (1..=25).collect();
+        assert_eq!(days, expected);
+    }
+
+    #[test]
+    fn test_get_solution() {
+        assert_eq!(get_solution(14).unwrap().title(), "Restroom Redoubt");
+        assert!(get_solution(26).is_none());
+    }
+}
@@ -1,10 +1,28 @@
-use clap::{Parser, Subcommand};
+use anyhow::{anyhow, Context, Result};
+use aoc24::{
+    check_day, check_summary, compare_baselines, comparison_table, criterion_dir, csv_records,
+    data_dir, enable_profiling, example_files, get_solution, json_records, new_day, profile_tree,
+    record_answer, results_table, run, run_all, solution_commands, write_data_files, Budgets, Day,
+    Fetched, InputSource, OutputFormat, Part, PuzzleClient, PuzzlePage, Recorded, RunResult,
+    SolutionArgs, Status, Submission, Watcher, SOLUTIONS,
+};
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
 use stderrlog::LogLevelNum;
 
 /*-------------------------------------------------------------------------------------------------
   Advent of Code 2024
 -------------------------------------------------------------------------------------------------*/
 
+// Count each parse's and solve's allocations (`cargo run --features count_allocations`)
+#[cfg(feature = "count_allocations")]
+#[global_allocator]
+static ALLOCATOR: aoc24::CountingAllocator = aoc24::CountingAllocator;
+
 /*--------------------------------------------------------------------------------------
   Command Line Interface (CLI)
 --------------------------------------------------------------------------------------*/
@@ -15,70 +33,182 @@ struct Args {
     #[arg(short, long, global = true)]
     pub debug: bool,
 
+    #[arg(short, long, global = true, value_enum, default_value_t)]
+    pub format: OutputFormat,
+
+    /// Time the solutions' profiling spans and print them as a tree (also enabled by --debug)
+    #[arg(long, global = true)]
+    pub profile: bool,
+
     #[command(subcommand)]
-    day: Days,
+    command: Option<Commands>,
 }
 
 #[derive(Subcommand)]
-enum Days {
-    #[clap(subcommand)]
-    Day1(aoc24::day1::Args),
-    #[clap(subcommand)]
-    Day2(aoc24::day2::Args),
-    #[clap(subcommand)]
-    Day3(aoc24::day3::Args),
-    #[clap(subcommand)]
-    Day4(aoc24::day4::Args),
-    #[clap(subcommand)]
-    Day5(aoc24::day5::Args),
-    #[clap(subcommand)]
-    Day6(aoc24::day6::Args),
-    #[clap(subcommand)]
-    Day7(aoc24::day7::Args),
-    #[clap(subcommand)]
-    Day8(aoc24::day8::Args),
-    #[clap(subcommand)]
-    Day9(aoc24::day9::Args),
-    #[clap(subcommand)]
-    Day10(aoc24::day10::Args),
-    #[clap(subcommand)]
-    Day11(aoc24::day11::Args),
-    #[clap(subcommand)]
-    Day12(aoc24::day12::Args),
-    #[clap(subcommand)]
-    Day13(aoc24::day13::Args),
-    #[clap(subcommand)]
-    Day14(aoc24::day14::Args),
-    #[clap(subcommand)]
-    Day15(aoc24::day15::Args),
-    #[clap(subcommand)]
-    Day16(aoc24::day16::Args),
-    #[clap(subcommand)]
-    Day17(aoc24::day17::Args),
-    #[clap(subcommand)]
-    Day18(aoc24::day18::Args),
-    #[clap(subcommand)]
-    Day19(aoc24::day19::Args),
-    #[clap(subcommand)]
-    Day20(aoc24::day20::Args),
-    #[clap(subcommand)]
-    Day21(aoc24::day21::Args),
-    #[clap(subcommand)]
-    Day22(aoc24::day22::Args),
-    #[clap(subcommand)]
-    Day23(aoc24::day23::Args),
-    #[clap(subcommand)]
-    Day24(aoc24::day24::Args),
-    #[clap(subcommand)]
-    Day25(aoc24::day25::Args),
+enum Commands {
+    /// Run every registered solution against its input and check the stored answers
+    ///
+    /// A parse or solve that runs past its timeout (from budgets.toml) can't be stopped and keeps
+    /// running in the background, so once one times out the remaining days and parts are skipped.
+    RunAll {
+        /// Number of days and parts to run concurrently
+        #[arg(short, long, default_value_t = 1)]
+        jobs: usize,
+
+        /// Data directory [default: $AOC_DATA_DIR or the nearest `data` directory]
+        #[arg(long)]
+        data: Option<PathBuf>,
+    },
+
+    /// Save and compare benchmark baselines
+    Bench {
+        #[command(subcommand)]
+        command: BenchCommand,
+    },
+
+    /// Run a day's solution against each of its examples and its input
+    Check {
+        /// Puzzle day (1-25)
+        day: Day,
+
+        /// Include inputs marked as slow
+        #[arg(long)]
+        slow: bool,
+
+        /// Data directory [default: $AOC_DATA_DIR or the nearest `data` directory]
+        #[arg(long)]
+        data: Option<PathBuf>,
+    },
+
+    /// Rebuild and check a day whenever its source or data files change
+    Watch {
+        /// Puzzle day (1-25)
+        day: Day,
+
+        /// Include inputs marked as slow
+        #[arg(long)]
+        slow: bool,
+
+        /// Data directory [default: $AOC_DATA_DIR or the nearest `data` directory]
+        #[arg(long)]
+        data: Option<PathBuf>,
+    },
+
+    /// Generate and register a new day module with placeholder data files
+    New {
+        /// Puzzle day (1-25)
+        day: Day,
+
+        /// Puzzle title
+        #[arg(long)]
+        title: String,
+
+        /// Data directory [default: $AOC_DATA_DIR or the nearest `data` directory]
+        #[arg(long)]
+        data: Option<PathBuf>,
+    },
+
+    /// Save the example inputs and answers from a saved puzzle description page
+    Examples {
+        /// Puzzle day (1-25)
+        day: Day,
+
+        /// Saved puzzle description (HTML)
+        page: PathBuf,
+
+        /// Code blocks to save, by their number in the preview [default: the first block]
+        #[arg(long, value_delimiter = ',')]
+        blocks: Vec<usize>,
+
+        /// Preview the code blocks and files without writing them
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Data directory [default: $AOC_DATA_DIR or the nearest `data` directory]
+        #[arg(long)]
+        data: Option<PathBuf>,
+    },
+
+    /// Download a day's puzzle input using the session token in $AOC_SESSION
+    Fetch {
+        /// Puzzle day (1-25)
+        day: Day,
+
+        /// Data directory [default: $AOC_DATA_DIR or the nearest `data` directory]
+        #[arg(long)]
+        data: Option<PathBuf>,
+
+        /// Puzzle site URL [default: $AOC_BASE_URL or https://adventofcode.com]
+        #[arg(long)]
+        base_url: Option<String>,
+    },
+
+    /// Submit an answer and record it when accepted, using the session token in $AOC_SESSION
+    Submit {
+        /// Puzzle day (1-25)
+        day: Day,
+
+        /// Puzzle part (part1 or part2)
+        part: Part,
+
+        /// Answer to submit [default: the solution's answer for the day's input]
+        answer: Option<String>,
+
+        /// Submit the answer even if the part already has a recorded answer
+        #[arg(long)]
+        force: bool,
+
+        /// Data directory [default: $AOC_DATA_DIR or the nearest `data` directory]
+        #[arg(long)]
+        data: Option<PathBuf>,
+
+        /// Puzzle site URL [default: $AOC_BASE_URL or https://adventofcode.com]
+        #[arg(long)]
+        base_url: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum BenchCommand {
+    /// Run the benchmarks and save the results as a named baseline
+    Save {
+        /// Baseline name (like a branch name or commit)
+        name: String,
+
+        /// Comma-separated days to benchmark [default: all days]
+        #[arg(long)]
+        days: Option<String>,
+    },
+
+    /// Print a Markdown table comparing the median times of two saved baselines
+    Compare {
+        /// Baseline to compare against
+        old: String,
+
+        /// Baseline to compare
+        new: String,
+
+        /// Criterion results directory [default: the nearest `target/criterion` directory]
+        #[arg(long)]
+        criterion_dir: Option<PathBuf>,
+    },
 }
 
 /*--------------------------------------------------------------------------------------
   Main
 --------------------------------------------------------------------------------------*/
 
-fn main() {
-    let args = Args::parse();
+fn main() -> ExitCode {
+    #[cfg(feature = "count_allocations")]
+    aoc24::CountingAllocator::activate();
+
+    // Register a subcommand for each solution
+    let matches = Args::command()
+        .subcommand_required(true)
+        .subcommands(solution_commands(SOLUTIONS))
+        .get_matches();
+
+    let args = Args::from_arg_matches(&matches).unwrap_or_else(|error| error.exit());
 
     // Configure logging
     stderrlog::new()
@@ -91,35 +221,424 @@ fn main() {
         .init()
         .unwrap();
 
-    let answer: Option<String> = match args.day {
-        Days::Day1(args) => aoc24::day1::main(args),
-        Days::Day2(args) => aoc24::day2::main(args),
-        Days::Day3(args) => aoc24::day3::main(args),
-        Days::Day4(args) => aoc24::day4::main(args),
-        Days::Day5(args) => aoc24::day5::main(args),
-        Days::Day6(args) => aoc24::day6::main(args),
-        Days::Day7(args) => aoc24::day7::main(args),
-        Days::Day8(args) => aoc24::day8::main(args),
-        Days::Day9(args) => aoc24::day9::main(args),
-        Days::Day10(args) => aoc24::day10::main(args),
-        Days::Day11(args) => aoc24::day11::main(args),
-        Days::Day12(args) => aoc24::day12::main(args),
-        Days::Day13(args) => aoc24::day13::main(args),
-        Days::Day14(args) => aoc24::day14::main(args),
-        Days::Day15(args) => aoc24::day15::main(args),
-        Days::Day16(args) => aoc24::day16::main(args),
-        Days::Day17(args) => aoc24::day17::main(args),
-        Days::Day18(args) => aoc24::day18::main(args),
-        Days::Day19(args) => aoc24::day19::main(args),
-        Days::Day20(args) => aoc24::day20::main(args),
-        Days::Day21(args) => aoc24::day21::main(args),
-        Days::Day22(args) => aoc24::day22::main(args),
-        Days::Day23(args) => aoc24::day23::main(args),
-        Days::Day24(args) => aoc24::day24::main(args),
-        Days::Day25(args) => aoc24::day25::main(args),
-    };
+    if args.profile || args.debug {
+        enable_profiling();
+    }
+
+    match args.command {
+        Some(Commands::RunAll { jobs, data }) => {
+            let data = match data.map_or_else(data_dir, Ok) {
+                Ok(data) => data,
+                Err(error) => {
+                    eprintln!("Error: {}", error);
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let budgets = match Budgets::load(&data) {
+                Ok(budgets) => budgets,
+                Err(error) => {
+                    eprintln!("Error: {:#}", error);
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let start = Instant::now();
+            let results = run_all(SOLUTIONS, &data, jobs, &budgets);
+            let elapsed = start.elapsed();
+
+            print_records(&results, args.format);
+            if args.format == OutputFormat::Text && jobs > 1 {
+                println!("Wall time: {elapsed:.2?} with {jobs} jobs");
+            }
+            if args.profile || args.debug {
+                eprint!("{}", profile_tree(&results));
+            }
+
+            if results.iter().any(|result| {
+                matches!(
+                    result.status(),
+                    Status::Fail | Status::Error | Status::OverBudget
+                )
+            }) {
+                ExitCode::FAILURE
+            } else {
+                ExitCode::SUCCESS
+            }
+        }
+        Some(Commands::Bench { command }) => {
+            let result = match command {
+                BenchCommand::Save { name, days } => save_baseline(&name, days.as_deref()),
+                BenchCommand::Compare {
+                    old,
+                    new,
+                    criterion_dir: directory,
+                } => directory
+                    .map_or_else(criterion_dir, Ok)
+                    .and_then(|directory| compare_baselines(&directory, &old, &new))
+                    .map(|comparisons| print!("{}", comparison_table(&comparisons, &old, &new))),
+            };
 
-    if let Some(answer) = answer {
-        println!("Answer: {}", answer);
+            match result {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(error) => {
+                    eprintln!("Error: {:#}", error);
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Some(Commands::Check { day, slow, data }) => {
+            let Some(solution) = get_solution(day) else {
+                eprintln!("Error: Day {day} has no registered solution");
+                return ExitCode::FAILURE;
+            };
+            let data = match data.map_or_else(data_dir, Ok) {
+                Ok(data) => data,
+                Err(error) => {
+                    eprintln!("Error: {}", error);
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let results = check_day(solution, &data, slow);
+
+            match args.format {
+                OutputFormat::Text => println!("{}", check_summary(&results)),
+                format => print_records(&results, format),
+            }
+
+            if results
+                .iter()
+                .any(|result| matches!(result.status(), Status::Fail | Status::Error))
+            {
+                ExitCode::FAILURE
+            } else {
+                ExitCode::SUCCESS
+            }
+        }
+        Some(Commands::Watch { day, slow, data }) => match watch(day, slow, data) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(error) => {
+                eprintln!("Error: {:#}", error);
+                ExitCode::FAILURE
+            }
+        },
+        Some(Commands::New { day, title, data }) => {
+            let package_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+            let created = data
+                .map_or_else(data_dir, Ok)
+                .and_then(|data| new_day(package_dir, &data, day, &title));
+
+            match created {
+                Ok(created) => {
+                    for path in created {
+                        println!("Wrote {}", path.display());
+                    }
+                    println!("Rebuild to run day {day}");
+                    ExitCode::SUCCESS
+                }
+                Err(error) => {
+                    eprintln!("Error: {}", error);
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Some(Commands::Examples {
+            day,
+            page,
+            blocks,
+            dry_run,
+            data,
+        }) => match examples(day, &page, &blocks, dry_run, data) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(error) => {
+                eprintln!("Error: {:#}", error);
+                ExitCode::FAILURE
+            }
+        },
+        Some(Commands::Fetch {
+            day,
+            data,
+            base_url,
+        }) => {
+            let fetched = data.map_or_else(data_dir, Ok).and_then(|data| {
+                PuzzleClient::from_env(base_url.as_deref(), &data)?.fetch_input(day)
+            });
+
+            match fetched {
+                Ok(Fetched::Cached(path)) => {
+                    println!("Using the existing input {}", path.display());
+                    ExitCode::SUCCESS
+                }
+                Ok(Fetched::Downloaded(path)) => {
+                    println!("Wrote {}", path.display());
+                    ExitCode::SUCCESS
+                }
+                Err(error) => {
+                    eprintln!("Error: {:#}", error);
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Some(Commands::Submit {
+            day,
+            part,
+            answer,
+            force,
+            data,
+            base_url,
+        }) => match submit(day, part, answer, force, data, base_url.as_deref()) {
+            Ok(Submission::Correct) => {
+                println!("Correct! Recorded the {part} answer for day {day}");
+                ExitCode::SUCCESS
+            }
+            Ok(Submission::Recorded(answer)) => {
+                println!("Already recorded the {part} answer for day {day}: {answer}");
+                ExitCode::SUCCESS
+            }
+            Ok(Submission::AlreadySolved) => {
+                println!("Day {day} {part} is already solved; the answer was not checked");
+                ExitCode::SUCCESS
+            }
+            Ok(
+                Submission::Incorrect(message)
+                | Submission::TooSoon(message)
+                | Submission::Unrecognized(message),
+            ) => {
+                eprintln!("{message}");
+                ExitCode::FAILURE
+            }
+            Err(error) => {
+                eprintln!("Error: {:#}", error);
+                ExitCode::FAILURE
+            }
+        },
+        None => {
+            let solution_args = match SolutionArgs::from_matches(SOLUTIONS, &matches) {
+                Ok(solution_args) => solution_args,
+                Err(error) => {
+                    eprintln!("Error: {:#}", error);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let result = run(
+                solution_args.solution,
+                solution_args.part,
+                &solution_args.input,
+                &solution_args.parameters,
+            );
+
+            if args.profile || args.debug {
+                eprint!("{}", profile_tree(std::slice::from_ref(&result)));
+            }
+
+            match args.format {
+                OutputFormat::Text => {
+                    if let Some(error) = &result.error {
+                        eprintln!("Error: {}", error);
+                    } else if let Some(answer) = &result.answer {
+                        println!("Answer: {}", answer);
+                    };
+                }
+                format => print_records(std::slice::from_ref(&result), format),
+            }
+
+            if result.error.is_some() {
+                return ExitCode::FAILURE;
+            }
+
+            if solution_args.record {
+                match record_answer(&result, solution_args.force) {
+                    Ok(Recorded::Written(path)) => log::info!("Recorded {}", path.display()),
+                    Ok(Recorded::Unchanged(path)) => {
+                        log::info!("{} is already up to date", path.display())
+                    }
+                    Ok(Recorded::Replaced { path, previous }) => log::warn!(
+                        "Replaced the answer in {}\n- {previous}\n+ {}",
+                        path.display(),
+                        result.answer.as_ref().unwrap()
+                    ),
+                    Err(error) => {
+                        eprintln!("Error: {:#}", error);
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+
+            ExitCode::SUCCESS
+        }
+    }
+}
+
+// Source changes are rebuilt first, and the checks run in the rebuilt executable
+fn watch(day: Day, slow: bool, data: Option<PathBuf>) -> Result<()> {
+    let data = data.map_or_else(data_dir, Ok)?;
+    let package_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let executable = std::env::current_exe().context("Failed to locate the aoc24 executable")?;
+
+    // Ctrl-C stops watching cleanly; the check it interrupts exits on its own
+    let stop = Arc::new(AtomicBool::new(false));
+    let handler_stop = Arc::clone(&stop);
+    ctrlc::set_handler(move || handler_stop.store(true, Ordering::Relaxed))
+        .context("Failed to handle Ctrl-C")?;
+
+    let mut watcher = Watcher::new(vec![
+        package_dir.join("src").join(format!("day{day}.rs")),
+        package_dir.join("src").join("shared"),
+        data.join(format!("day{day}")),
+    ]);
+    let mut rebuild = true;
+
+    loop {
+        if rebuild && !build(package_dir) {
+            println!("Build failed");
+        } else {
+            Command::new(&executable)
+                .arg("check")
+                .arg(day.to_string())
+                .arg("--data")
+                .arg(&data)
+                .args(slow.then_some("--slow"))
+                .status()
+                .context("Failed to run the checks")?;
+        }
+
+        if stop.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+
+        println!("Watching day {day} for changes (press Ctrl-C to stop)");
+        let Some(changed) = watcher.wait(&stop) else {
+            return Ok(());
+        };
+        println!();
+        for path in &changed {
+            println!("Changed {}", path.display());
+        }
+
+        rebuild = changed.iter().any(|path| path.starts_with(package_dir));
+    }
+}
+
+// Builds with the current executable's profile, so the rebuilt executable replaces it
+fn build(package_dir: &Path) -> bool {
+    let mut command = cargo_command(package_dir, "build");
+    command.args(["--quiet", "--bin", env!("CARGO_BIN_NAME")]);
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+
+    command.status().is_ok_and(|status| status.success())
+}
+
+fn save_baseline(name: &str, days: Option<&str>) -> Result<()> {
+    let package_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut command = cargo_command(package_dir, "bench");
+    command.args(["--bench", "solutions", "--", "--save-baseline", name]);
+    if let Some(days) = days {
+        command.env("AOC_BENCH_DAYS", days);
+    }
+
+    let status = command.status().context("Failed to run cargo bench")?;
+    if !status.success() {
+        return Err(anyhow!("cargo bench failed ({status})"));
+    }
+
+    println!("Saved the {name:?} baseline");
+    Ok(())
+}
+
+fn cargo_command(package_dir: &Path, subcommand: &str) -> Command {
+    let mut command = Command::new(std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    command
+        .arg(subcommand)
+        .arg("--manifest-path")
+        .arg(package_dir.join("Cargo.toml"));
+    command
+}
+
+fn examples(
+    day: Day,
+    page: &Path,
+    blocks: &[usize],
+    dry_run: bool,
+    data: Option<PathBuf>,
+) -> Result<()> {
+    let html = std::fs::read_to_string(page)
+        .with_context(|| format!("Failed to read {}", page.display()))?;
+    let page = PuzzlePage::parse(&html)?;
+    let day_dir = data.map_or_else(data_dir, Ok)?.join(format!("day{day}"));
+    let files = example_files(&page, &day_dir, blocks)?;
+
+    if !dry_run {
+        for path in write_data_files(&files)? {
+            println!("Wrote {}", path.display());
+        }
+        return Ok(());
+    }
+
+    for (index, code_block) in page.code_blocks.iter().enumerate() {
+        println!("Block {index} ({}):", code_block.part);
+        print_indented(&code_block.text);
+    }
+    for answer in &page.answers {
+        match answer.block {
+            Some(block) => println!(
+                "Example answer ({}, block {block}): {}",
+                answer.part, answer.answer
+            ),
+            None => println!("Example answer ({}): {}", answer.part, answer.answer),
+        }
+    }
+    for file in &files {
+        println!("Would write {}:", file.path.display());
+        print_indented(&file.contents);
+    }
+
+    Ok(())
+}
+
+fn print_indented(text: &str) {
+    for line in text.lines() {
+        println!("    {line}");
+    }
+}
+
+// Without an explicit answer, the day's solution is run against its input
+fn submit(
+    day: Day,
+    part: Part,
+    answer: Option<String>,
+    force: bool,
+    data: Option<PathBuf>,
+    base_url: Option<&str>,
+) -> Result<Submission> {
+    let data = data.map_or_else(data_dir, Ok)?;
+    let client = PuzzleClient::from_env(base_url, &data)?;
+
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let solution =
+                get_solution(day).ok_or_else(|| anyhow!("Day {day} has no registered solution"))?;
+            let input = InputSource::File(client.input_path(day));
+            let result = run(solution, part, &input, &solution.default_parameters());
+
+            match (result.answer, result.error) {
+                (Some(answer), _) => answer.to_string(),
+                (None, error) => return Err(anyhow!(error.unwrap_or_default())),
+            }
+        }
     };
+
+    log::info!("Submitting {answer} for day {day} {part}");
+    client.submit_answer(day, part, &answer, force)
+}
+
+fn print_records(results: &[RunResult], format: OutputFormat) {
+    match format {
+        OutputFormat::Json => println!("{}", json_records(results)),
+        OutputFormat::Csv => println!("{}", csv_records(results)),
+        OutputFormat::Text => println!("{}", results_table(results)),
+    }
 }
@@ -0,0 +1,170 @@
+use serde::Serialize;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/*-------------------------------------------------------------------------------------------------
+  Allocation Counting
+-------------------------------------------------------------------------------------------------*/
+
+// Install the counting allocator in a binary (like `aoc24` does with the `count_allocations`
+// feature) to record the allocations made while parsing and solving. The counters are per thread,
+// so concurrent runs don't count each other's allocations.
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct AllocationStats {
+    pub allocations: u64,
+    pub allocated_bytes: u64,
+    pub peak_bytes: u64,
+}
+
+pub struct CountingAllocator;
+
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+    static ALLOCATED_BYTES: Cell<u64> = const { Cell::new(0) };
+    // Memory freed on another thread can take a thread's heap size below zero
+    static HEAP_BYTES: Cell<i64> = const { Cell::new(0) };
+    static PEAK_HEAP_BYTES: Cell<i64> = const { Cell::new(0) };
+}
+
+fn record_allocation(size: usize) {
+    ALLOCATIONS.set(ALLOCATIONS.get() + 1);
+    ALLOCATED_BYTES.set(ALLOCATED_BYTES.get() + size as u64);
+    record_heap_change(size as i64);
+}
+
+fn record_heap_change(change: i64) {
+    let heap_bytes = HEAP_BYTES.get() + change;
+    HEAP_BYTES.set(heap_bytes);
+    if heap_bytes > PEAK_HEAP_BYTES.get() {
+        PEAK_HEAP_BYTES.set(heap_bytes);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_heap_change(-(layout.size() as i64));
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            // A reallocation counts as an allocation of the new size
+            ALLOCATIONS.set(ALLOCATIONS.get() + 1);
+            ALLOCATED_BYTES.set(ALLOCATED_BYTES.get() + new_size as u64);
+            record_heap_change(new_size as i64 - layout.size() as i64);
+        }
+        new_ptr
+    }
+}
+
+impl CountingAllocator {
+    // Called by the binary before running anything, so unmeasured runs can be told apart
+    pub fn activate() {
+        ACTIVE.store(true, Ordering::Relaxed);
+    }
+}
+
+// Measures the allocations `f` makes on this thread, and its peak heap growth. Returns no stats
+// unless the counting allocator is active.
+pub fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, Option<AllocationStats>) {
+    if !ACTIVE.load(Ordering::Relaxed) {
+        return (f(), None);
+    }
+
+    let (result, stats) = measure(f);
+    (result, Some(stats))
+}
+
+fn measure<T>(f: impl FnOnce() -> T) -> (T, AllocationStats) {
+    let allocations = ALLOCATIONS.get();
+    let allocated_bytes = ALLOCATED_BYTES.get();
+    let heap_bytes = HEAP_BYTES.get();
+    let peak_heap_bytes = PEAK_HEAP_BYTES.replace(heap_bytes);
+
+    let result = f();
+
+    let peak = PEAK_HEAP_BYTES.get();
+    PEAK_HEAP_BYTES.set(peak.max(peak_heap_bytes));
+
+    let stats = AllocationStats {
+        allocations: ALLOCATIONS.get() - allocations,
+        allocated_bytes: ALLOCATED_BYTES.get() - allocated_bytes,
+        peak_bytes: (peak - heap_bytes).max(0) as u64,
+    };
+
+    (result, stats)
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{bytes}B")
+    } else {
+        format!("{value:.2}{}", UNITS[unit])
+    }
+}
+
+/*-------------------------------------------------------------------------------------------------
+  Tests
+-------------------------------------------------------------------------------------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512B");
+        assert_eq!(format_bytes(1536), "1.50KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.00MiB");
+    }
+
+    #[test]
+    fn test_count_allocations() {
+        // The test harness doesn't install the counting allocator, so its hooks are called directly
+        let (_, stats) = measure(|| {
+            record_allocation(100);
+            record_allocation(50);
+            record_heap_change(-100);
+            record_allocation(20);
+        });
+
+        assert_eq!(
+            stats,
+            AllocationStats {
+                allocations: 3,
+                allocated_bytes: 170,
+                peak_bytes: 150,
+            }
+        );
+    }
+}
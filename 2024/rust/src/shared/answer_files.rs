@@ -0,0 +1,80 @@
+use std::fs;
+use std::path::Path;
+
+/*-------------------------------------------------------------------------------------------------
+  Answer Files
+-------------------------------------------------------------------------------------------------*/
+
+// A day's answers are saved as `<stem>-partN-answer.txt` files, checked against the solution's
+// answer for the `<stem>-partN.txt` or `<stem>.txt` input. A `<stem>-partN.slow` or `<stem>.slow`
+// marker file flags a slow part.
+//
+// `build.rs` includes this module with `#[path]` to generate the data tests, so it only uses `std`.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnswerCase {
+    pub stem: String,
+    pub part: u8,
+    pub answer_file: String,
+    // The input file's name, if the day directory has one for the answer
+    pub input_file: Option<String>,
+    pub slow: bool,
+}
+
+// The day's non-empty answer files, in file name order
+pub fn answer_cases(day_dir: &Path) -> Vec<AnswerCase> {
+    file_names(day_dir)
+        .into_iter()
+        .filter_map(|answer_file| {
+            let (stem, part) = parse_answer_file_name(&answer_file)?;
+
+            // Placeholder answer files are empty until the answer is known
+            if fs::read_to_string(day_dir.join(&answer_file))
+                .is_ok_and(|answer| answer.trim().is_empty())
+            {
+                return None;
+            }
+
+            let input_file = [format!("{stem}-part{part}.txt"), format!("{stem}.txt")]
+                .into_iter()
+                .find(|input_file| day_dir.join(input_file).is_file());
+
+            Some(AnswerCase {
+                stem: stem.to_string(),
+                part,
+                slow: is_slow(day_dir, stem, part),
+                input_file,
+                answer_file,
+            })
+        })
+        .collect()
+}
+
+pub fn is_slow(day_dir: &Path, stem: &str, part: u8) -> bool {
+    [format!("{stem}-part{part}.slow"), format!("{stem}.slow")]
+        .iter()
+        .any(|marker| day_dir.join(marker).is_file())
+}
+
+fn file_names(directory: &Path) -> Vec<String> {
+    let mut file_names: Vec<String> = fs::read_dir(directory)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect();
+
+    file_names.sort();
+    file_names
+}
+
+fn parse_answer_file_name(file_name: &str) -> Option<(&str, u8)> {
+    let (stem, part) = file_name
+        .strip_suffix("-answer.txt")?
+        .rsplit_once("-part")?;
+
+    match part.parse() {
+        Ok(part @ 1..=2) => Some((stem, part)),
+        _ => None,
+    }
+}
@@ -1,9 +1,102 @@
+use crate::shared::data_dir::locate_data_file;
+use itertools::Itertools;
+use std::fmt::{Display, Formatter};
 use std::path::Path;
 
 /*-------------------------------------------------------------------------------------------------
   Answers
 -------------------------------------------------------------------------------------------------*/
 
+// Empty answer files (like those created by `aoc24 new`) are treated as missing answers
 pub fn get_answer<P: AsRef<Path> + ?Sized>(file_path: &P) -> Option<String> {
-    Some(std::fs::read_to_string(file_path).ok()?.trim().to_string())
+    let file_path = locate_data_file(file_path.as_ref());
+    let answer = std::fs::read_to_string(file_path).ok()?.trim().to_string();
+    (!answer.is_empty()).then_some(answer)
+}
+
+/*--------------------------------------------------------------------------------------
+  Answer
+--------------------------------------------------------------------------------------*/
+
+// Displayed in the same format as the puzzle answers, so the answer files compare as text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i64),
+    BigInteger(i128),
+    Text(String),
+    Coordinate(i64, i64),
+    List(Vec<String>),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Integer(value) => write!(f, "{value}"),
+            Self::BigInteger(value) => write!(f, "{value}"),
+            Self::Text(text) => write!(f, "{text}"),
+            Self::Coordinate(x, y) => write!(f, "{x},{y}"),
+            Self::List(items) => write!(f, "{}", items.iter().join(",")),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(value: $integer) -> Self {
+                    match i64::try_from(value) {
+                        Ok(value) => Self::Integer(value),
+                        Err(_) => Self::BigInteger(value as i128),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl<T: ToString> FromIterator<T> for Answer {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::List(iter.into_iter().map(|item| item.to_string()).collect())
+    }
+}
+
+/*-------------------------------------------------------------------------------------------------
+  Tests
+-------------------------------------------------------------------------------------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::from(11u8).to_string(), "11");
+        assert_eq!(Answer::from(-3i32), Answer::Integer(-3));
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInteger(u64::MAX as i128));
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from("4,6,3").to_string(), "4,6,3");
+        assert_eq!(Answer::Coordinate(6, 1).to_string(), "6,1");
+        assert_eq!(
+            ["aa", "bb", "cc"]
+                .into_iter()
+                .collect::<Answer>()
+                .to_string(),
+            "aa,bb,cc"
+        );
+    }
 }
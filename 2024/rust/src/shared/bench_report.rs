@@ -0,0 +1,265 @@
+use anyhow::{anyhow, bail, Context, Result};
+use serde::Deserialize;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/*-------------------------------------------------------------------------------------------------
+  Benchmark Baseline Reports
+-------------------------------------------------------------------------------------------------*/
+
+// Criterion saves each benchmark's results as `<group>/<benchmark>/<baseline>/estimates.json`
+// under `target/criterion`, where the groups are the days (see `benches/solutions.rs`).
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct Estimate {
+    pub point_estimate: f64,
+    pub confidence_interval: ConfidenceInterval,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct ConfidenceInterval {
+    pub lower_bound: f64,
+    pub upper_bound: f64,
+}
+
+#[derive(Deserialize)]
+struct Estimates {
+    median: Estimate,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub group: String,
+    pub benchmark: String,
+    pub old: Option<Estimate>,
+    pub new: Option<Estimate>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    Faster,
+    Slower,
+    NoChange,
+    Added,
+    Removed,
+}
+
+impl Comparison {
+    pub fn change_ratio(&self) -> Option<f64> {
+        let (old, new) = (self.old?, self.new?);
+        Some(new.point_estimate / old.point_estimate - 1.0)
+    }
+
+    // A change is significant when the medians' 95% confidence intervals don't overlap
+    pub fn change(&self) -> Change {
+        match (self.old, self.new) {
+            (Some(old), Some(new)) => {
+                if new.confidence_interval.lower_bound > old.confidence_interval.upper_bound {
+                    Change::Slower
+                } else if new.confidence_interval.upper_bound < old.confidence_interval.lower_bound
+                {
+                    Change::Faster
+                } else {
+                    Change::NoChange
+                }
+            }
+            (None, _) => Change::Added,
+            (_, None) => Change::Removed,
+        }
+    }
+}
+
+/*--------------------------------------------------------------------------------------
+  Baselines
+--------------------------------------------------------------------------------------*/
+
+// `$CARGO_TARGET_DIR/criterion`, or the nearest `target/criterion` above the package
+pub fn criterion_dir() -> Result<PathBuf> {
+    if let Some(target_dir) = std::env::var_os("CARGO_TARGET_DIR") {
+        return Ok(PathBuf::from(target_dir).join("criterion"));
+    }
+
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .ancestors()
+        .map(|directory| directory.join("target").join("criterion"))
+        .find(|criterion_dir| criterion_dir.is_dir())
+        .ok_or_else(|| anyhow!("Could not find the criterion results; run the benchmarks first"))
+}
+
+pub fn compare_baselines(criterion_dir: &Path, old: &str, new: &str) -> Result<Vec<Comparison>> {
+    let mut comparisons = Vec::new();
+
+    for group in sub_directories(criterion_dir)? {
+        for benchmark in sub_directories(&criterion_dir.join(&group))? {
+            let benchmark_dir = criterion_dir.join(&group).join(&benchmark);
+            let old = read_median(&benchmark_dir, old)?;
+            let new = read_median(&benchmark_dir, new)?;
+
+            if old.is_some() || new.is_some() {
+                comparisons.push(Comparison {
+                    group: group.clone(),
+                    benchmark,
+                    old,
+                    new,
+                });
+            }
+        }
+    }
+
+    if comparisons.is_empty() {
+        bail!("No benchmarks found for the {old:?} or {new:?} baselines in {criterion_dir:?}");
+    }
+
+    comparisons.sort_by_key(|comparison| {
+        (
+            day_number(&comparison.group),
+            comparison.group.clone(),
+            comparison.benchmark.clone(),
+        )
+    });
+    Ok(comparisons)
+}
+
+fn sub_directories(directory: &Path) -> Result<Vec<String>> {
+    let entries =
+        fs::read_dir(directory).with_context(|| format!("Failed to read {directory:?}"))?;
+
+    Ok(entries
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| name != "report")
+        .collect())
+}
+
+fn read_median(benchmark_dir: &Path, baseline: &str) -> Result<Option<Estimate>> {
+    let path = benchmark_dir.join(baseline).join("estimates.json");
+    if !path.is_file() {
+        return Ok(None);
+    }
+
+    let text = fs::read_to_string(&path).with_context(|| format!("Failed to read {path:?}"))?;
+    let estimates: Estimates =
+        serde_json::from_str(&text).with_context(|| format!("Invalid estimates in {path:?}"))?;
+    Ok(Some(estimates.median))
+}
+
+// Sorts `day2` before `day10`
+fn day_number(group: &str) -> u32 {
+    group
+        .strip_prefix("day")
+        .and_then(|day| day.parse().ok())
+        .unwrap_or(u32::MAX)
+}
+
+/*--------------------------------------------------------------------------------------
+  Comparison Table
+--------------------------------------------------------------------------------------*/
+
+// A Markdown table of the median times, for pasting into a review
+pub fn comparison_table(comparisons: &[Comparison], old: &str, new: &str) -> String {
+    let mut table = String::new();
+    writeln!(table, "| Day | Benchmark | {old} | {new} | Change | |").unwrap();
+    writeln!(table, "|-----|-----------|------:|------:|-------:|-|").unwrap();
+
+    for comparison in comparisons {
+        let median = |estimate: Option<Estimate>| {
+            estimate.map_or_else(
+                || "-".to_string(),
+                |estimate| {
+                    format!(
+                        "{:.2?}",
+                        Duration::from_secs_f64(estimate.point_estimate / 1e9)
+                    )
+                },
+            )
+        };
+        let change_ratio = comparison.change_ratio().map_or_else(
+            || "-".to_string(),
+            |ratio| format!("{:+.2}%", ratio * 100.0),
+        );
+        let change = match comparison.change() {
+            Change::Faster => "faster",
+            Change::Slower => "slower",
+            Change::NoChange => "",
+            Change::Added => "added",
+            Change::Removed => "removed",
+        };
+
+        writeln!(
+            table,
+            "| {} | {} | {} | {} | {change_ratio} | {change} |",
+            comparison.group,
+            comparison.benchmark,
+            median(comparison.old),
+            median(comparison.new),
+        )
+        .unwrap();
+    }
+
+    table
+}
+
+/*-------------------------------------------------------------------------------------------------
+  Tests
+-------------------------------------------------------------------------------------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_estimate(criterion_dir: &Path, benchmark: &str, baseline: &str, median: f64) {
+        let directory = criterion_dir.join(benchmark).join(baseline);
+        fs::create_dir_all(&directory).unwrap();
+        let estimate = serde_json::json!({
+            "point_estimate": median,
+            "confidence_interval": {
+                "confidence_level": 0.95,
+                "lower_bound": median * 0.98,
+                "upper_bound": median * 1.02,
+            },
+            "standard_error": 1.0,
+        });
+        let estimates = serde_json::json!({"mean": estimate, "median": estimate});
+        fs::write(directory.join("estimates.json"), estimates.to_string()).unwrap();
+    }
+
+    #[test]
+    fn test_compare_baselines() {
+        let criterion_dir =
+            std::env::temp_dir().join(format!("aoc24-bench-report-{}", std::process::id()));
+        write_estimate(&criterion_dir, "day10/part1", "before", 2000.0);
+        write_estimate(&criterion_dir, "day10/part1", "after", 1000.0);
+        write_estimate(&criterion_dir, "day2/parse", "before", 1000.0);
+        write_estimate(&criterion_dir, "day2/parse", "after", 1010.0);
+        write_estimate(&criterion_dir, "day2/part2", "after", 5e6);
+        write_estimate(&criterion_dir, "day2/part2", "new", 5e6);
+        fs::create_dir_all(criterion_dir.join("report")).unwrap();
+
+        let comparisons = compare_baselines(&criterion_dir, "before", "after").unwrap();
+        let changes: Vec<_> = comparisons
+            .iter()
+            .map(|comparison| comparison.change())
+            .collect();
+        assert_eq!(changes, [Change::NoChange, Change::Added, Change::Faster]);
+        assert_eq!(comparisons[2].change_ratio(), Some(-0.5));
+
+        assert_eq!(
+            comparison_table(&comparisons, "before", "after"),
+            "\
+| Day | Benchmark | before | after | Change | |
+|-----|-----------|------:|------:|-------:|-|
+| day2 | parse | 1.00µs | 1.01µs | +1.00% |  |
+| day2 | part2 | - | 5.00ms | - | added |
+| day10 | part1 | 2.00µs | 1.00µs | -50.00% | faster |
+"
+        );
+
+        assert!(compare_baselines(&criterion_dir, "before", "missing").is_ok());
+        assert!(compare_baselines(&criterion_dir, "missing", "unknown").is_err());
+
+        fs::remove_dir_all(criterion_dir).unwrap();
+    }
+}
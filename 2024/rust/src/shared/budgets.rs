@@ -0,0 +1,167 @@
+use crate::shared::solution::Day;
+use anyhow::{anyhow, bail, Context, Result};
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::time::Duration;
+
+/*-------------------------------------------------------------------------------------------------
+  Time Budgets
+-------------------------------------------------------------------------------------------------*/
+
+// `budgets.toml` in the data directory sets each day's time budget (for parsing its input and
+// solving its parts) and the hard timeout for any single parse or solve:
+//
+//     [default]
+//     budget = "1s"
+//     timeout = "1m"
+//
+//     [day6]
+//     budget = "3s"
+//
+// A day's settings override the defaults. Durations are a number followed by `ms`, `s`, or `m`.
+pub const BUDGETS_FILE: &str = "budgets.toml";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Budget {
+    pub budget: Option<Duration>,
+    pub timeout: Option<Duration>,
+}
+
+impl Budget {
+    fn or(self, default: Budget) -> Self {
+        Self {
+            budget: self.budget.or(default.budget),
+            timeout: self.timeout.or(default.timeout),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Budgets {
+    default: Budget,
+    days: HashMap<Day, Budget>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BudgetEntry {
+    budget: Option<String>,
+    timeout: Option<String>,
+}
+
+impl Budgets {
+    // Without a budgets file, nothing is budgeted
+    pub fn load(data_dir: &Path) -> Result<Self> {
+        let path = data_dir.join(BUDGETS_FILE);
+        if !path.is_file() {
+            return Ok(Self::default());
+        }
+
+        let text =
+            std::fs::read_to_string(&path).with_context(|| format!("Failed to read {path:?}"))?;
+        Self::parse(&text).with_context(|| format!("Invalid time budgets in {path:?}"))
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        let entries: BTreeMap<String, BudgetEntry> = toml::from_str(text)?;
+
+        let mut budgets = Self::default();
+        for (name, entry) in entries {
+            let budget = Budget {
+                budget: entry.budget.as_deref().map(parse_duration).transpose()?,
+                timeout: entry.timeout.as_deref().map(parse_duration).transpose()?,
+            };
+
+            if name == "default" {
+                budgets.default = budget;
+            } else {
+                let day = name
+                    .strip_prefix("day")
+                    .and_then(|day| day.parse().ok())
+                    .ok_or_else(|| {
+                        anyhow!("Unknown section [{name}]; expected [default] or [dayN]")
+                    })?;
+                budgets.days.insert(day, budget);
+            }
+        }
+
+        Ok(budgets)
+    }
+
+    pub fn get(&self, day: Day) -> Budget {
+        self.days
+            .get(&day)
+            .copied()
+            .unwrap_or_default()
+            .or(self.default)
+    }
+}
+
+fn parse_duration(text: &str) -> Result<Duration> {
+    let text = text.trim();
+    let (number, unit_seconds) = if let Some(number) = text.strip_suffix("ms") {
+        (number, 0.001)
+    } else if let Some(number) = text.strip_suffix('s') {
+        (number, 1.0)
+    } else if let Some(number) = text.strip_suffix('m') {
+        (number, 60.0)
+    } else {
+        bail!("Invalid duration {text:?}; expected a number followed by ms, s, or m");
+    };
+
+    let number: f64 = number
+        .trim()
+        .parse()
+        .map_err(|_| anyhow!("Invalid duration {text:?}"))?;
+    Duration::try_from_secs_f64(number * unit_seconds)
+        .map_err(|_| anyhow!("Invalid duration {text:?}"))
+}
+
+/*-------------------------------------------------------------------------------------------------
+  Tests
+-------------------------------------------------------------------------------------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_budgets() {
+        let budgets = Budgets::parse(
+            r#"
+            [default]
+            budget = "1s"
+            timeout = "1.5m"
+
+            [day6]
+            budget = "250ms"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            budgets.get(6),
+            Budget {
+                budget: Some(Duration::from_millis(250)),
+                timeout: Some(Duration::from_secs(90)),
+            }
+        );
+        assert_eq!(budgets.get(7).budget, Some(Duration::from_secs(1)));
+        assert_eq!(Budgets::parse("").unwrap().get(6), Budget::default());
+
+        assert!(Budgets::parse("[day6]\nbudget = \"3 hours\"").is_err());
+        assert!(Budgets::parse("[sixth]\nbudget = \"3s\"").is_err());
+        assert!(Budgets::parse("[day6]\nlimit = \"3s\"").is_err());
+    }
+
+    #[test]
+    fn test_data_budgets() {
+        let data_dir = crate::shared::data_dir::data_dir().unwrap();
+        let budgets = Budgets::load(&data_dir).unwrap();
+
+        // Every day has a hard timeout, and the slow days have more time than the default
+        assert!(budgets.get(1).timeout.is_some());
+        assert!(budgets.get(6).budget > budgets.get(1).budget);
+    }
+}
@@ -0,0 +1,258 @@
+use crate::shared::data_dir::{data_parameters, day_file};
+use crate::shared::inputs::InputSource;
+use crate::shared::solution::{Parameter, ParameterKind, Parameters, Part, Solution};
+use anyhow::{anyhow, Result};
+use clap::parser::ValueSource;
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
+use std::path::PathBuf;
+
+/*-------------------------------------------------------------------------------------------------
+  Solution Command Line Interface
+-------------------------------------------------------------------------------------------------*/
+
+pub struct SolutionArgs {
+    pub solution: &'static dyn Solution,
+    pub part: Part,
+    pub input: InputSource,
+    pub parameters: Parameters,
+    pub record: bool,
+    pub force: bool,
+}
+
+/*--------------------------------------------------------------------------------------
+  Command Builders
+--------------------------------------------------------------------------------------*/
+
+pub fn solution_commands(solutions: &[&'static dyn Solution]) -> Vec<Command> {
+    solutions
+        .iter()
+        .map(|solution| solution_command(*solution))
+        .collect()
+}
+
+pub fn solution_command(solution: &dyn Solution) -> Command {
+    Command::new(solution.name())
+        .about(solution.long_title())
+        .long_about(solution.long_title())
+        .subcommand_required(true)
+        .subcommands(
+            solution
+                .parts()
+                .iter()
+                .map(|part| part_command(*part, solution.parameters())),
+        )
+}
+
+fn part_command(part: Part, parameters: &'static [Parameter]) -> Command {
+    let parameters: Vec<&Parameter> = parameters
+        .iter()
+        .filter(|parameter| parameter.applies_to(part))
+        .collect();
+
+    // A parameter may claim `-h` (like day 14's height), leaving help as `--help` only
+    let help_short = !parameters
+        .iter()
+        .any(|parameter| parameter.short == Some('h'));
+
+    Command::new(part.to_string())
+        .disable_help_flag(true)
+        .arg(
+            Arg::new("file")
+                .help("Puzzle input file, or - to read from stdin")
+                .required_unless_present_any(["input", "inline"])
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            Arg::new("input")
+                .long("input")
+                .value_name("NAME")
+                .help("Use the named file from the day's data directory (like input or example)")
+                .conflicts_with("file"),
+        )
+        .arg(
+            Arg::new("inline")
+                .long("inline")
+                .value_name("TEXT")
+                .help("Use TEXT as the puzzle input")
+                .conflicts_with_all(["file", "input"]),
+        )
+        .arg(
+            Arg::new("record")
+                .long("record")
+                .help("Write the answer to the input's answer file")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("force")
+                .long("force")
+                .help("Replace a different recorded answer")
+                .action(ArgAction::SetTrue)
+                .requires("record"),
+        )
+        .args(parameters.into_iter().map(parameter_arg))
+        .arg(
+            Arg::new("help")
+                .long("help")
+                .short(help_short.then_some('h'))
+                .help("Print help")
+                .action(ArgAction::Help),
+        )
+}
+
+fn parameter_arg(parameter: &Parameter) -> Arg {
+    let arg = Arg::new(parameter.name)
+        .long(parameter.name)
+        .short(parameter.short)
+        .help(parameter.help);
+
+    match parameter.kind {
+        ParameterKind::Value => arg.default_value(parameter.default),
+        ParameterKind::Flag => arg.action(ArgAction::SetTrue),
+    }
+}
+
+/*--------------------------------------------------------------------------------------
+  Argument Parsing
+--------------------------------------------------------------------------------------*/
+
+impl SolutionArgs {
+    pub fn from_matches(solutions: &[&'static dyn Solution], matches: &ArgMatches) -> Result<Self> {
+        let unresolved = || anyhow!("Expected a day, part and input to run (see --help)");
+
+        let (name, solution_matches) = matches.subcommand().ok_or_else(unresolved)?;
+        let solution = *solutions
+            .iter()
+            .find(|solution| solution.name() == name)
+            .ok_or_else(unresolved)?;
+
+        let (part_name, part_matches) = solution_matches.subcommand().ok_or_else(unresolved)?;
+        let part: Part = part_name.parse()?;
+
+        let input = if let Some(text) = part_matches.get_one::<String>("inline") {
+            InputSource::Inline(text.clone())
+        } else if let Some(name) = part_matches.get_one::<String>("input") {
+            InputSource::File(day_file(solution.day(), part, name))
+        } else {
+            part_matches
+                .get_one::<PathBuf>("file")
+                .ok_or_else(unresolved)?
+                .clone()
+                .into()
+        };
+
+        // Flags given on the command line override an input file's `<stem>.params`
+        let mut parameters = match &input {
+            InputSource::File(path) => data_parameters(solution, path)?,
+            _ => solution.default_parameters(),
+        };
+
+        for parameter in solution.parameters() {
+            if !parameter.applies_to(part)
+                || part_matches.value_source(parameter.name) != Some(ValueSource::CommandLine)
+            {
+                continue;
+            }
+
+            let value = match parameter.kind {
+                ParameterKind::Value => part_matches
+                    .get_one::<String>(parameter.name)
+                    .ok_or_else(unresolved)?
+                    .clone(),
+                ParameterKind::Flag => part_matches.get_flag(parameter.name).to_string(),
+            };
+            parameters.set(parameter.name, &value);
+        }
+
+        Ok(Self {
+            solution,
+            part,
+            input,
+            parameters,
+            record: part_matches.get_flag("record"),
+            force: part_matches.get_flag("force"),
+        })
+    }
+}
+
+/*-------------------------------------------------------------------------------------------------
+  Tests
+-------------------------------------------------------------------------------------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SOLUTIONS;
+
+    fn parse(args: &[&str]) -> SolutionArgs {
+        let matches = Command::new("aoc24")
+            .subcommands(solution_commands(SOLUTIONS))
+            .try_get_matches_from(std::iter::once("aoc24").chain(args.iter().copied()))
+            .unwrap();
+        SolutionArgs::from_matches(SOLUTIONS, &matches).unwrap()
+    }
+
+    #[test]
+    fn test_input_sources() {
+        let args = parse(&["day1", "part1", "input.txt"]);
+        assert_eq!(args.input, InputSource::File(PathBuf::from("input.txt")));
+        assert!(!args.record);
+
+        let args = parse(&["day1", "part1", "--input", "input", "--record", "--force"]);
+        assert!(args.record && args.force);
+
+        let args = parse(&["day1", "part2", "-"]);
+        assert_eq!(args.input, InputSource::Stdin);
+
+        let args = parse(&["day3", "part2", "--input", "example"]);
+        assert!(args
+            .input
+            .path()
+            .unwrap()
+            .ends_with("day3/example-part2.txt"));
+
+        let args = parse(&[
+            "day14",
+            "part1",
+            "--inline",
+            "p=0,4 v=3,-3",
+            "--width",
+            "11",
+        ]);
+        assert_eq!(args.input, InputSource::Inline("p=0,4 v=3,-3".to_string()));
+        assert_eq!(args.parameters.get::<isize>("width").unwrap(), 11);
+    }
+
+    #[test]
+    fn test_input_parameters() {
+        // day18/example.params sets the example's size and bytes
+        let args = parse(&["day18", "part1", "--input", "example"]);
+        assert_eq!(args.parameters.get::<usize>("size").unwrap(), 6);
+        assert_eq!(args.parameters.get::<usize>("bytes").unwrap(), 12);
+
+        let args = parse(&["day18", "part1", "--input", "example", "--bytes", "20"]);
+        assert_eq!(args.parameters.get::<usize>("size").unwrap(), 6);
+        assert_eq!(args.parameters.get::<usize>("bytes").unwrap(), 20);
+
+        let args = parse(&["day18", "part1", "--inline", "5,4"]);
+        assert_eq!(args.parameters.get::<usize>("size").unwrap(), 70);
+    }
+
+    #[test]
+    fn test_part_parameters() {
+        let args = parse(&["day14", "part1", "input.txt", "-w", "11", "-h", "7"]);
+        assert_eq!(args.parameters.get::<isize>("width").unwrap(), 11);
+        assert_eq!(args.parameters.get::<isize>("height").unwrap(), 7);
+
+        let args = parse(&["day14", "part2", "input.txt", "-v"]);
+        assert!(args.parameters.get::<bool>("visualize").unwrap());
+
+        let command = Command::new("aoc24").subcommands(solution_commands(SOLUTIONS));
+        assert!(command
+            .clone()
+            .try_get_matches_from(["aoc24", "day14", "part1", "input.txt", "--visualize"])
+            .is_err());
+        assert!(command
+            .try_get_matches_from(["aoc24", "day14", "part2", "input.txt", "--width", "11"])
+            .is_err());
+    }
+}
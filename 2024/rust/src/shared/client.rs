@@ -0,0 +1,417 @@
+use crate::shared::html;
+use crate::shared::runner::answer_path;
+use crate::shared::solution::{Day, Part};
+use anyhow::{anyhow, bail, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/*-------------------------------------------------------------------------------------------------
+  Puzzle Client
+-------------------------------------------------------------------------------------------------*/
+
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const YEAR: u16 = 2024;
+const USER_AGENT: &str = concat!(
+    "aoc24/",
+    env!("CARGO_PKG_VERSION"),
+    " (github.com/cmlccie/advent-of-code)"
+);
+
+// Minimum time between requests, shared across runs through the timestamp in the rate limit file
+const REQUEST_INTERVAL: Duration = Duration::from_secs(5);
+const RATE_LIMIT_FILE: &str = "aoc24-last-request";
+
+pub struct PuzzleClient {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    data_dir: PathBuf,
+    request_interval: Duration,
+    rate_limit_file: PathBuf,
+}
+
+impl PuzzleClient {
+    pub fn new(base_url: &str, session: &str, data_dir: &Path) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            data_dir: data_dir.to_path_buf(),
+            request_interval: REQUEST_INTERVAL,
+            rate_limit_file: std::env::temp_dir().join(RATE_LIMIT_FILE),
+        }
+    }
+
+    // The base URL defaults to $AOC_BASE_URL, then the Advent of Code site
+    pub fn from_env(base_url: Option<&str>, data_dir: &Path) -> Result<Self> {
+        let session = std::env::var(SESSION_ENV)
+            .map_err(|_| anyhow!("Set {SESSION_ENV} to your Advent of Code session token"))?;
+        let base_url = match base_url {
+            Some(base_url) => base_url.to_string(),
+            None => std::env::var(BASE_URL_ENV).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string()),
+        };
+
+        Ok(Self::new(&base_url, &session, data_dir))
+    }
+
+    pub fn with_request_interval(mut self, request_interval: Duration) -> Self {
+        self.request_interval = request_interval;
+        self
+    }
+
+    pub fn with_rate_limit_file(mut self, rate_limit_file: &Path) -> Self {
+        self.rate_limit_file = rate_limit_file.to_path_buf();
+        self
+    }
+
+    /*-----------------------------------------------------------------------------
+      Inputs
+    -----------------------------------------------------------------------------*/
+
+    pub fn input_path(&self, day: Day) -> PathBuf {
+        self.data_dir.join(format!("day{day}")).join("input.txt")
+    }
+
+    // Existing inputs are never downloaded again
+    pub fn fetch_input(&self, day: Day) -> Result<Fetched> {
+        validate_day(day)?;
+
+        let input_path = self.input_path(day);
+        if input_path.exists() {
+            return Ok(Fetched::Cached(input_path));
+        }
+
+        let url = format!("{}/{YEAR}/day/{day}/input", self.base_url);
+        let input = self.request(&url, None)?;
+        if input.trim().is_empty() {
+            bail!("{url} returned an empty input");
+        }
+
+        let day_dir = input_path.parent().unwrap();
+        fs::create_dir_all(day_dir).with_context(|| format!("Failed to create {day_dir:?}"))?;
+        fs::write(&input_path, input).with_context(|| format!("Failed to write {input_path:?}"))?;
+
+        Ok(Fetched::Downloaded(input_path))
+    }
+
+    /*-----------------------------------------------------------------------------
+      Answers
+    -----------------------------------------------------------------------------*/
+
+    // Accepted answers are recorded next to the input, so a recorded part is only resubmitted when
+    // `force` is set; a different answer for a recorded part is an error without it
+    pub fn submit_answer(
+        &self,
+        day: Day,
+        part: Part,
+        answer: &str,
+        force: bool,
+    ) -> Result<Submission> {
+        validate_day(day)?;
+
+        let answer = answer.trim();
+        if answer.is_empty() {
+            bail!("The answer must not be empty");
+        }
+
+        let answer_path = answer_path(&self.input_path(day), part);
+        if let Ok(recorded) = fs::read_to_string(&answer_path) {
+            let recorded = recorded.trim();
+            if !recorded.is_empty() && !force {
+                if recorded != answer {
+                    bail!(
+                        "Day {day} {part} has the recorded answer {recorded}, so {answer} was not \
+                         submitted (use --force to submit it anyway)"
+                    );
+                }
+                return Ok(Submission::Recorded(recorded.to_string()));
+            }
+        }
+
+        // Make sure an accepted answer can be recorded before submitting it
+        let day_dir = answer_path.parent().unwrap();
+        fs::create_dir_all(day_dir).with_context(|| format!("Failed to create {day_dir:?}"))?;
+
+        let url = format!("{}/{YEAR}/day/{day}/answer", self.base_url);
+        let level = part.number().to_string();
+        let response = self.request(&url, Some(&[("level", &level), ("answer", answer)]))?;
+
+        let submission = Submission::from_response(&response);
+        if submission == Submission::Correct {
+            fs::write(&answer_path, format!("{answer}\n"))
+                .with_context(|| format!("Failed to write {answer_path:?}"))?;
+        }
+
+        Ok(submission)
+    }
+
+    /*-----------------------------------------------------------------------------
+      Requests
+    -----------------------------------------------------------------------------*/
+
+    // Form data is sent as a POST; requests without it are a GET
+    fn request(&self, url: &str, form: Option<&[(&str, &str)]>) -> Result<String> {
+        if self.session.is_empty() {
+            bail!("The session token must not be empty");
+        }
+
+        self.wait_for_rate_limit();
+
+        log::debug!("Requesting {url}");
+        let cookie = format!("session={}", self.session);
+        let response = match form {
+            Some(form) => self.agent.post(url).set("Cookie", &cookie).send_form(form),
+            None => self.agent.get(url).set("Cookie", &cookie).call(),
+        };
+
+        // Failed requests count toward the rate limit too
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let _ = fs::write(&self.rate_limit_file, now.as_millis().to_string());
+
+        match response {
+            Ok(response) => response
+                .into_string()
+                .with_context(|| format!("Failed to read the response from {url}")),
+            Err(ureq::Error::Status(code, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                bail!("{url} returned HTTP {code}: {}", body.trim())
+            }
+            Err(error) => Err(error).with_context(|| format!("Failed to request {url}")),
+        }
+    }
+
+    fn wait_for_rate_limit(&self) {
+        let elapsed = fs::read_to_string(&self.rate_limit_file)
+            .ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis))
+            .and_then(|last_request| SystemTime::now().duration_since(last_request).ok());
+
+        if let Some(wait) = elapsed.and_then(|elapsed| self.request_interval.checked_sub(elapsed)) {
+            log::info!("Waiting {wait:.1?} before the next request");
+            std::thread::sleep(wait);
+        }
+    }
+}
+
+fn validate_day(day: Day) -> Result<()> {
+    if !(1..=25).contains(&day) {
+        bail!("Invalid day {day}; expected 1 through 25");
+    }
+    Ok(())
+}
+
+/*--------------------------------------------------------------------------------------
+  Outcomes
+--------------------------------------------------------------------------------------*/
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+impl Fetched {
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Cached(path) | Self::Downloaded(path) => path,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Submission {
+    Correct,
+    Incorrect(String),
+    TooSoon(String),
+    AlreadySolved,
+    Recorded(String),
+    Unrecognized(String),
+}
+
+impl Submission {
+    // Classifies the response by the messages the puzzle site shows after a submission
+    fn from_response(response: &str) -> Self {
+        let message = html::articles(response)
+            .first()
+            .map_or(response, |article| article);
+        let message = html::text(message);
+        let message = message.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        if message.contains("That's the right answer") {
+            Self::Correct
+        } else if message.contains("not the right answer") {
+            Self::Incorrect(message)
+        } else if message.contains("answer too recently") {
+            Self::TooSoon(message)
+        } else if message.contains("Did you already complete it") {
+            Self::AlreadySolved
+        } else {
+            Self::Unrecognized(message)
+        }
+    }
+}
+
+/*-------------------------------------------------------------------------------------------------
+  Tests
+-------------------------------------------------------------------------------------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread::JoinHandle;
+
+    struct Request {
+        method: String,
+        url: String,
+        cookie: Option<String>,
+        body: String,
+    }
+
+    // Serves the given responses in order, then shuts down so any further request fails
+    fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<Request>>) {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+
+        let handle = std::thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let mut request = server.recv().unwrap();
+                let mut request_body = String::new();
+                request
+                    .as_reader()
+                    .read_to_string(&mut request_body)
+                    .unwrap();
+                requests.push(Request {
+                    method: request.method().to_string(),
+                    url: request.url().to_string(),
+                    cookie: request
+                        .headers()
+                        .iter()
+                        .find(|header| header.field.equiv("Cookie"))
+                        .map(|header| header.value.to_string()),
+                    body: request_body,
+                });
+                request
+                    .respond(tiny_http::Response::from_string(body).with_status_code(status))
+                    .unwrap();
+            }
+            requests
+        });
+
+        (base_url, handle)
+    }
+
+    fn test_client(base_url: &str, name: &str) -> (PuzzleClient, PathBuf) {
+        let root = std::env::temp_dir().join(format!("aoc24-client-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let client = PuzzleClient::new(base_url, "token", &root.join("data"))
+            .with_request_interval(Duration::ZERO)
+            .with_rate_limit_file(&root.join(RATE_LIMIT_FILE));
+        (client, root)
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let (base_url, server) = serve(vec![(200, "1 2\n3 4\n")]);
+        let (client, root) = test_client(&base_url, "fetch");
+
+        let fetched = client.fetch_input(5).unwrap();
+        assert!(matches!(fetched, Fetched::Downloaded(_)));
+        assert_eq!(fs::read_to_string(fetched.path()).unwrap(), "1 2\n3 4\n");
+
+        // The cached input is returned without another request
+        assert_eq!(
+            client.fetch_input(5).unwrap(),
+            Fetched::Cached(client.input_path(5))
+        );
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].url, "/2024/day/5/input");
+        assert_eq!(requests[0].cookie.as_deref(), Some("session=token"));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_input_error() {
+        let (base_url, server) = serve(vec![(400, "Please log in to get your puzzle input.")]);
+        let (client, root) = test_client(&base_url, "fetch-error");
+
+        let error = client.fetch_input(5).unwrap_err().to_string();
+        assert!(error.contains("HTTP 400"));
+        assert!(!client.input_path(5).exists());
+
+        server.join().unwrap();
+        let _ = fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let (base_url, server) = serve(vec![
+            (
+                200,
+                "<main><article><p>That's not the right answer; your answer is too high.</p></article></main>",
+            ),
+            (
+                200,
+                "<main><article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article></main>",
+            ),
+            (
+                200,
+                "<main><article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article></main>",
+            ),
+        ]);
+        let (client, root) = test_client(&base_url, "submit");
+        // The day's data directory doesn't exist yet
+        let answer_path = root.join("data/day5/input-part1-answer.txt");
+
+        let submission = client.submit_answer(5, Part::Part1, "143", false).unwrap();
+        assert_eq!(
+            submission,
+            Submission::Incorrect(
+                "That's not the right answer; your answer is too high.".to_string()
+            )
+        );
+        assert!(!answer_path.exists());
+
+        let submission = client.submit_answer(5, Part::Part1, "42", false).unwrap();
+        assert_eq!(submission, Submission::Correct);
+        assert_eq!(fs::read_to_string(&answer_path).unwrap(), "42\n");
+
+        // Recorded answers are not submitted again, and different answers need --force
+        assert_eq!(
+            client.submit_answer(5, Part::Part1, "42", false).unwrap(),
+            Submission::Recorded("42".to_string())
+        );
+        let error = client
+            .submit_answer(5, Part::Part1, "43", false)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("recorded answer 42, so 43 was not submitted"));
+
+        let submission = client.submit_answer(5, Part::Part1, "43", true).unwrap();
+        assert_eq!(submission, Submission::AlreadySolved);
+        assert_eq!(fs::read_to_string(&answer_path).unwrap(), "42\n");
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[1].method, "POST");
+        assert_eq!(requests[1].url, "/2024/day/5/answer");
+        assert_eq!(requests[1].body, "level=1&answer=42");
+        assert_eq!(requests[2].body, "level=1&answer=43");
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
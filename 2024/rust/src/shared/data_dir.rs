@@ -0,0 +1,113 @@
+use crate::shared::solution::{Day, Parameters, Part, Solution};
+use anyhow::{anyhow, bail, Result};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/*-------------------------------------------------------------------------------------------------
+  Data Directory
+-------------------------------------------------------------------------------------------------*/
+
+pub const DATA_DIR_ENV: &str = "AOC_DATA_DIR";
+
+// Directory names checked at each level when searching upward for the puzzle data
+const DATA_DIR_CANDIDATES: [&str; 2] = ["data", "2024/data"];
+
+static DATA_DIR_CELL: OnceLock<Option<PathBuf>> = OnceLock::new();
+
+pub fn data_dir() -> Result<PathBuf> {
+    DATA_DIR_CELL
+        .get_or_init(find_data_dir)
+        .clone()
+        .ok_or_else(|| anyhow!("Could not locate the data directory; set {DATA_DIR_ENV}"))
+}
+
+fn find_data_dir() -> Option<PathBuf> {
+    if let Some(data_dir) = std::env::var_os(DATA_DIR_ENV) {
+        return Some(PathBuf::from(data_dir));
+    }
+
+    search_upward(Path::new(env!("CARGO_MANIFEST_DIR"))).or_else(|| {
+        let executable = std::env::current_exe().ok()?;
+        search_upward(executable.parent()?)
+    })
+}
+
+fn search_upward(start: &Path) -> Option<PathBuf> {
+    start.ancestors().find_map(|directory| {
+        DATA_DIR_CANDIDATES
+            .iter()
+            .map(|candidate| directory.join(candidate))
+            .find(|data_dir| data_dir.is_dir())
+    })
+}
+
+/*--------------------------------------------------------------------------------------
+  Data Files
+--------------------------------------------------------------------------------------*/
+
+pub fn locate_data_file(path: &Path) -> PathBuf {
+    if path.exists() || path.is_absolute() {
+        return path.to_path_buf();
+    }
+
+    match data_dir() {
+        Ok(data_dir) => data_dir.join(path),
+        Err(_) => path.to_path_buf(),
+    }
+}
+
+pub fn day_file(day: Day, part: Part, name: &str) -> PathBuf {
+    let day_dir = PathBuf::from(format!("day{day}"));
+
+    // Part-specific files (like day 3's `example-part2.txt`) take precedence
+    let part_file = locate_data_file(&day_dir.join(format!("{name}-{part}.txt")));
+    if part_file.is_file() {
+        return part_file;
+    }
+
+    locate_data_file(&day_dir.join(format!("{name}.txt")))
+}
+
+// Example inputs may override the solution's default parameters with a `<stem>.params` file of
+// `name = value` lines
+pub fn data_parameters(solution: &dyn Solution, input_path: &Path) -> Result<Parameters> {
+    let mut parameters = solution.default_parameters();
+
+    let parameters_path = locate_data_file(&input_path.with_extension("params"));
+    if let Ok(text) = std::fs::read_to_string(&parameters_path) {
+        for line in text.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let Some((name, value)) = line.split_once('=') else {
+                bail!("Invalid parameter in {parameters_path:?}: {line:?}");
+            };
+            parameters.set(name.trim(), value.trim());
+        }
+    }
+
+    Ok(parameters)
+}
+
+/*-------------------------------------------------------------------------------------------------
+  Tests
+-------------------------------------------------------------------------------------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_upward() {
+        let data_dir = search_upward(Path::new(env!("CARGO_MANIFEST_DIR"))).unwrap();
+        assert!(data_dir.ends_with("2024/data"));
+    }
+
+    #[test]
+    fn test_day_file() {
+        assert!(day_file(3, Part::Part2, "example").ends_with("day3/example-part2.txt"));
+        assert!(day_file(5, Part::Part1, "example").ends_with("day5/example.txt"));
+        assert!(day_file(5, Part::Part1, "input").ends_with("day5/input.txt"));
+    }
+}
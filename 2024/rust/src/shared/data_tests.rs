@@ -0,0 +1,59 @@
+#![allow(dead_code)] // No tests are generated when the data directory has no answer files
+use crate::get_solution;
+use crate::shared::answers::get_answer;
+use crate::shared::budgets::Budgets;
+use crate::shared::data_dir::{data_dir, data_parameters};
+use crate::shared::inputs::get_input;
+use crate::shared::runner::{answer_path, run_with_timeout};
+use crate::shared::solution::Part;
+use std::path::Path;
+use std::sync::OnceLock;
+use std::time::Instant;
+
+/*-------------------------------------------------------------------------------------------------
+  Data Tests
+-------------------------------------------------------------------------------------------------*/
+
+// Tests generated by `build.rs` from the input and answer files in the data directory
+include!(concat!(env!("OUT_DIR"), "/data_tests.rs"));
+
+// The hard timeout always applies. Debug builds are too slow to hold to the time budgets, so
+// those are checked by `cargo test --release`, against each part's parse and solve time.
+fn check_answer(day: u8, part: Part, input_path: &str) {
+    let solution = get_solution(day).unwrap();
+    let input_path = Path::new(input_path);
+    let budget = budgets().get(day);
+
+    let input = get_input(input_path).unwrap();
+    let parameters = data_parameters(solution, input_path).unwrap();
+
+    let (answer, elapsed) = run_with_timeout(budget.timeout, move || {
+        let start = Instant::now();
+        let input = solution.parse(&input)?;
+        let answer = solution.solve(part, &input, &parameters)?;
+        anyhow::Ok((answer, start.elapsed()))
+    })
+    .unwrap_or_else(|error| panic!("Day {day} {part}: {error}"))
+    .unwrap();
+
+    assert_eq!(
+        Some(answer.to_string()),
+        get_answer(&answer_path(input_path, part))
+    );
+
+    if let Some(limit) = budget.budget.filter(|_| !cfg!(debug_assertions)) {
+        assert!(
+            elapsed <= limit,
+            "Day {day} {part} took {elapsed:.2?}, over its {limit:.2?} budget"
+        );
+    }
+}
+
+fn budgets() -> &'static Budgets {
+    static BUDGETS_CELL: OnceLock<Budgets> = OnceLock::new();
+    BUDGETS_CELL.get_or_init(|| {
+        data_dir()
+            .map(|data_dir| Budgets::load(&data_dir).unwrap())
+            .unwrap_or_default()
+    })
+}
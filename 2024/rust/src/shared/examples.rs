@@ -0,0 +1,367 @@
+use crate::shared::html;
+use crate::shared::runner::answer_path;
+use crate::shared::solution::Part;
+use anyhow::{bail, Context, Result};
+use regex::Regex;
+use std::fs;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/*-------------------------------------------------------------------------------------------------
+  Puzzle Examples
+-------------------------------------------------------------------------------------------------*/
+
+static CODE_BLOCK_REGEX_CELL: OnceLock<Regex> = OnceLock::new();
+static EMPHASIZED_CODE_REGEX_CELL: OnceLock<Regex> = OnceLock::new();
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeBlock {
+    pub part: Part,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExampleAnswer {
+    pub part: Part,
+    pub answer: String,
+    // The last code block before the answer in the part's description, which is its example
+    pub block: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PuzzlePage {
+    pub code_blocks: Vec<CodeBlock>,
+    pub answers: Vec<ExampleAnswer>,
+}
+
+impl PuzzlePage {
+    // Each part's description is an `<article>`; its last emphasized code (`<code><em>` or
+    // `<em><code>`) outside the code blocks is taken as the part's example answer
+    pub fn parse(html: &str) -> Result<Self> {
+        let code_block_regex = CODE_BLOCK_REGEX_CELL
+            .get_or_init(|| Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap());
+        let emphasized_code_regex = EMPHASIZED_CODE_REGEX_CELL.get_or_init(|| {
+            Regex::new(r"<code><em>([^<]*)</em></code>|<em><code>([^<]*)</code></em>").unwrap()
+        });
+
+        let articles = html::articles(html);
+        if articles.is_empty() {
+            bail!("No puzzle description found; expected the page's <article> elements");
+        }
+
+        let mut code_blocks = Vec::new();
+        let mut answers = Vec::new();
+
+        for (article, part) in articles.into_iter().zip([Part::Part1, Part::Part2]) {
+            let first_block = code_blocks.len();
+            let block_spans: Vec<Range<usize>> = code_block_regex
+                .captures_iter(article)
+                .map(|captures| {
+                    code_blocks.push(CodeBlock {
+                        part,
+                        text: html::text(&captures[1]),
+                    });
+                    captures.get(0).unwrap().range()
+                })
+                .collect();
+
+            let answer = emphasized_code_regex
+                .captures_iter(article)
+                .filter(|captures| {
+                    let start = captures.get(0).unwrap().start();
+                    !block_spans.iter().any(|span| span.contains(&start))
+                })
+                .last()
+                .and_then(|captures| captures.get(1).or_else(|| captures.get(2)));
+
+            if let Some(answer) = answer {
+                let text = html::text(answer.as_str()).trim().to_string();
+                let preceding = block_spans
+                    .iter()
+                    .filter(|span| span.end <= answer.start())
+                    .count();
+
+                if !text.is_empty() {
+                    answers.push(ExampleAnswer {
+                        part,
+                        answer: text,
+                        block: preceding.checked_sub(1).map(|block| first_block + block),
+                    });
+                }
+            }
+        }
+
+        Ok(Self {
+            code_blocks,
+            answers,
+        })
+    }
+}
+
+/*--------------------------------------------------------------------------------------
+  Data Files
+--------------------------------------------------------------------------------------*/
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataFile {
+    pub path: PathBuf,
+    pub contents: String,
+}
+
+// The first selected block (by default, the page's first block) is the main example and gets the
+// part 1 answer. A single block is saved as `example.txt`; several are numbered `example0.txt`, ...
+//
+// The part 2 answer also goes with the main example, unless part 2's description has a different
+// example, which is saved alongside it as `example-part2.txt` (or `exampleN-part2.txt`).
+pub fn example_files(page: &PuzzlePage, day_dir: &Path, blocks: &[usize]) -> Result<Vec<DataFile>> {
+    let blocks = if blocks.is_empty() { &[0] } else { blocks };
+
+    let mut files = Vec::new();
+    for (position, &block) in blocks.iter().enumerate() {
+        let Some(code_block) = page.code_blocks.get(block) else {
+            bail!(
+                "Block {block} does not exist; the page has {} code blocks",
+                page.code_blocks.len()
+            );
+        };
+
+        let stem = if blocks.len() == 1 {
+            "example".to_string()
+        } else {
+            format!("example{position}")
+        };
+        let input_path = day_dir.join(format!("{stem}.txt"));
+
+        if position == 0 {
+            for answer in &page.answers {
+                let part_example = answer
+                    .block
+                    .filter(|_| answer.part == Part::Part2)
+                    .map(|block| &page.code_blocks[block])
+                    .filter(|example| example.text != code_block.text);
+
+                let answer_input_path = match part_example {
+                    Some(example) => {
+                        let path = day_dir.join(format!("{stem}-{}.txt", answer.part));
+                        files.push(DataFile {
+                            path: path.clone(),
+                            contents: example.text.clone(),
+                        });
+                        path
+                    }
+                    None => input_path.clone(),
+                };
+
+                files.push(DataFile {
+                    path: answer_path(&answer_input_path, answer.part),
+                    contents: format!("{}\n", answer.answer),
+                });
+            }
+        }
+
+        files.push(DataFile {
+            path: input_path,
+            contents: code_block.text.clone(),
+        });
+    }
+
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(files)
+}
+
+// Empty placeholder files are replaced and files with the same contents are left alone. Nothing is
+// written if any other file already exists.
+pub fn write_data_files(files: &[DataFile]) -> Result<Vec<PathBuf>> {
+    let mut pending = Vec::new();
+    for file in files {
+        match fs::read_to_string(&file.path) {
+            Ok(existing) if existing.trim() == file.contents.trim() => continue,
+            Ok(existing) if !existing.trim().is_empty() => {
+                bail!("{:?} already exists with different contents", file.path)
+            }
+            _ => pending.push(file),
+        }
+    }
+
+    let mut written = Vec::new();
+    for file in pending {
+        if let Some(directory) = file.path.parent() {
+            fs::create_dir_all(directory)
+                .with_context(|| format!("Failed to create {directory:?}"))?;
+        }
+        fs::write(&file.path, &file.contents)
+            .with_context(|| format!("Failed to write {:?}", file.path))?;
+        written.push(file.path.clone());
+    }
+
+    Ok(written)
+}
+
+/*-------------------------------------------------------------------------------------------------
+  Tests
+-------------------------------------------------------------------------------------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2>
+<p>For example:</p>
+<pre><code>3   4
+4   3
+</code></pre>
+<p>Pair up <code>3</code> and <code>4</code>:</p>
+<pre><code><em>3</em> &lt; 4
+</code></pre>
+<p>The total distance is <code><em>11</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>1234</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>The similarity score is <em><code>31</code></em>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_puzzle_page() {
+        let page = PuzzlePage::parse(PAGE).unwrap();
+        assert_eq!(
+            page.code_blocks,
+            [
+                CodeBlock {
+                    part: Part::Part1,
+                    text: "3   4\n4   3\n".to_string()
+                },
+                CodeBlock {
+                    part: Part::Part1,
+                    text: "3 < 4\n".to_string()
+                },
+            ]
+        );
+        assert_eq!(
+            page.answers,
+            [
+                ExampleAnswer {
+                    part: Part::Part1,
+                    answer: "11".to_string(),
+                    block: Some(1)
+                },
+                ExampleAnswer {
+                    part: Part::Part2,
+                    answer: "31".to_string(),
+                    block: None
+                },
+            ]
+        );
+
+        assert!(PuzzlePage::parse("<p>Not a puzzle</p>").is_err());
+    }
+
+    #[test]
+    fn test_example_files() {
+        let page = PuzzlePage::parse(PAGE).unwrap();
+        let day_dir = Path::new("day1");
+
+        let files = example_files(&page, day_dir, &[]).unwrap();
+        let paths: Vec<_> = files
+            .iter()
+            .map(|file| file.path.to_str().unwrap())
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                "day1/example-part1-answer.txt",
+                "day1/example-part2-answer.txt",
+                "day1/example.txt"
+            ]
+        );
+        assert_eq!(files[0].contents, "11\n");
+
+        let files = example_files(&page, day_dir, &[1, 0]).unwrap();
+        let paths: Vec<_> = files
+            .iter()
+            .map(|file| file.path.to_str().unwrap())
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                "day1/example0-part1-answer.txt",
+                "day1/example0-part2-answer.txt",
+                "day1/example0.txt",
+                "day1/example1.txt"
+            ]
+        );
+        assert_eq!(files[2].contents, "3 < 4\n");
+
+        assert!(example_files(&page, day_dir, &[2]).is_err());
+    }
+
+    #[test]
+    fn test_part2_example_files() {
+        let page = PuzzlePage::parse(
+            r#"<article><pre><code>mul(2,4)</code></pre><p>Total: <code><em>8</em></code></p></article>
+<article><pre><code>don't()mul(2,4)</code></pre><p>Total: <code><em>0</em></code></p></article>"#,
+        )
+        .unwrap();
+        assert_eq!(page.answers[1].block, Some(1));
+
+        let day_dir = Path::new("day3");
+        let files: Vec<_> = example_files(&page, day_dir, &[])
+            .unwrap()
+            .into_iter()
+            .map(|file| (file.path.to_str().unwrap().to_string(), file.contents))
+            .collect();
+        assert_eq!(
+            files,
+            [
+                (
+                    "day3/example-part1-answer.txt".to_string(),
+                    "8\n".to_string()
+                ),
+                (
+                    "day3/example-part2-answer.txt".to_string(),
+                    "0\n".to_string()
+                ),
+                (
+                    "day3/example-part2.txt".to_string(),
+                    "don't()mul(2,4)".to_string()
+                ),
+                ("day3/example.txt".to_string(), "mul(2,4)".to_string()),
+            ]
+        );
+
+        // A part 2 example that repeats the main example isn't saved again
+        let page = PuzzlePage::parse(
+            r#"<article><pre><code>1 2</code></pre><p><code><em>3</em></code></p></article>
+<article><pre><code>1 2</code></pre><p><code><em>2</em></code></p></article>"#,
+        )
+        .unwrap();
+        let files = example_files(&page, day_dir, &[]).unwrap();
+        assert_eq!(files.len(), 3);
+        assert!(files[1].path.ends_with("example-part2-answer.txt"));
+    }
+
+    #[test]
+    fn test_write_data_files() {
+        let day_dir = std::env::temp_dir().join(format!("aoc24-examples-{}", std::process::id()));
+        fs::create_dir_all(&day_dir).unwrap();
+
+        // Placeholders are replaced
+        fs::write(day_dir.join("example.txt"), "").unwrap();
+        let page = PuzzlePage::parse(PAGE).unwrap();
+        let files = example_files(&page, &day_dir, &[]).unwrap();
+        assert_eq!(write_data_files(&files).unwrap().len(), 3);
+        assert_eq!(write_data_files(&files).unwrap().len(), 0);
+
+        // Existing examples are never overwritten
+        let files = example_files(&page, &day_dir, &[1]).unwrap();
+        assert!(write_data_files(&files).is_err());
+        assert_eq!(
+            fs::read_to_string(day_dir.join("example.txt")).unwrap(),
+            "3   4\n4   3\n"
+        );
+
+        fs::remove_dir_all(day_dir).unwrap();
+    }
+}
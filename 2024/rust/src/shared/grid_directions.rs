@@ -114,3 +114,113 @@ impl From<GridDirection> for char {
         }
     }
 }
+
+/*--------------------------------------------------------------------------------------
+  OctalDirection
+--------------------------------------------------------------------------------------*/
+
+// The four cardinal and four diagonal directions, in clockwise order from Up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
+pub enum OctalDirection {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl OctalDirection {
+    pub fn is_diagonal(&self) -> bool {
+        matches!(
+            self,
+            Self::UpRight | Self::DownRight | Self::DownLeft | Self::UpLeft
+        )
+    }
+
+    // Turns 45 degrees
+    pub fn turn_clockwise(&self) -> Self {
+        match self {
+            Self::Up => Self::UpRight,
+            Self::UpRight => Self::Right,
+            Self::Right => Self::DownRight,
+            Self::DownRight => Self::Down,
+            Self::Down => Self::DownLeft,
+            Self::DownLeft => Self::Left,
+            Self::Left => Self::UpLeft,
+            Self::UpLeft => Self::Up,
+        }
+    }
+
+    // Turns 45 degrees
+    pub fn turn_counterclockwise(&self) -> Self {
+        match self {
+            Self::Up => Self::UpLeft,
+            Self::UpLeft => Self::Left,
+            Self::Left => Self::DownLeft,
+            Self::DownLeft => Self::Down,
+            Self::Down => Self::DownRight,
+            Self::DownRight => Self::Right,
+            Self::Right => Self::UpRight,
+            Self::UpRight => Self::Up,
+        }
+    }
+
+    pub fn opposite(&self) -> Self {
+        self.turn_clockwise()
+            .turn_clockwise()
+            .turn_clockwise()
+            .turn_clockwise()
+    }
+}
+
+/*-----------------------------------------------------------------------------
+  Implement AnyDirection
+-----------------------------------------------------------------------------*/
+
+impl<I: Signed> AnyDirection<I> for OctalDirection {
+    fn offset(&self) -> GridIndex<I> {
+        let (row, column) = match self {
+            Self::Up => (I::one().neg(), I::zero()),
+            Self::UpRight => (I::one().neg(), I::one()),
+            Self::Right => (I::zero(), I::one()),
+            Self::DownRight => (I::one(), I::one()),
+            Self::Down => (I::one(), I::zero()),
+            Self::DownLeft => (I::one(), I::one().neg()),
+            Self::Left => (I::zero(), I::one().neg()),
+            Self::UpLeft => (I::one().neg(), I::one().neg()),
+        };
+        GridIndex::new(row, column)
+    }
+}
+
+/*-----------------------------------------------------------------------------
+  Type Conversions
+-----------------------------------------------------------------------------*/
+
+impl From<GridDirection> for OctalDirection {
+    fn from(direction: GridDirection) -> Self {
+        match direction {
+            GridDirection::Up => Self::Up,
+            GridDirection::Down => Self::Down,
+            GridDirection::Left => Self::Left,
+            GridDirection::Right => Self::Right,
+        }
+    }
+}
+
+impl TryFrom<OctalDirection> for GridDirection {
+    type Error = Error;
+
+    fn try_from(direction: OctalDirection) -> Result<Self, Self::Error> {
+        match direction {
+            OctalDirection::Up => Ok(Self::Up),
+            OctalDirection::Down => Ok(Self::Down),
+            OctalDirection::Left => Ok(Self::Left),
+            OctalDirection::Right => Ok(Self::Right),
+            diagonal => Err(anyhow!("{diagonal:?} is not a GridDirection")),
+        }
+    }
+}
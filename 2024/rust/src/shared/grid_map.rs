@@ -1,5 +1,5 @@
 #![allow(dead_code)]
-use crate::shared::grid_directions::AnyDirection;
+use crate::shared::grid_directions::{AnyDirection, GridDirection, OctalDirection};
 use crate::shared::grid_index::GridIndex;
 use anyhow::{anyhow, Result};
 use itertools::Itertools;
@@ -7,12 +7,13 @@ use num::{Integer, Signed};
 use std::cell::OnceCell;
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
+use strum::IntoEnumIterator;
 
 /*-------------------------------------------------------------------------------------------------
   Map
 -------------------------------------------------------------------------------------------------*/
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct GridMap<I, T>
 where
     I: Integer + Copy + TryInto<usize> + TryFrom<usize>,
@@ -76,6 +77,11 @@ where
         self.data.chunks(self.bounds.column.try_into().unwrap())
     }
 
+    pub fn columns_iter(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let columns: usize = self.bounds.column.try_into().unwrap();
+        (0..columns).map(move |column| self.data.iter().skip(column).step_by(columns))
+    }
+
     /*-------------------------------------------------------------------------
       Value Methods
     -------------------------------------------------------------------------*/
@@ -128,6 +134,22 @@ where
         self.get(new_index)
     }
 
+    /*-------------------------------------------------------------------------
+      Neighbor Methods
+    -------------------------------------------------------------------------*/
+
+    // The in-bounds positions at each offset from `index`, in the offsets' order
+    pub fn neighbors_with<'a>(
+        &'a self,
+        index: GridIndex<I>,
+        offsets: &'a [GridIndex<I>],
+    ) -> impl Iterator<Item = (GridIndex<I>, &'a T)> + 'a {
+        offsets.iter().filter_map(move |&offset| {
+            let neighbor = index + offset;
+            Some((neighbor, self.get(neighbor)?))
+        })
+    }
+
     /*-------------------------------------------------------------------------
       Index Methods
     -------------------------------------------------------------------------*/
@@ -192,6 +214,316 @@ where
         let offset = direction.offset();
         self.get_offset(index, offset)
     }
+
+    // The in-bounds orthogonal neighbors, in `GridDirection` order
+    pub fn neighbors(&self, index: GridIndex<I>) -> impl Iterator<Item = (GridIndex<I>, &T)> {
+        self.direction_neighbors(index, GridDirection::iter())
+    }
+
+    // The in-bounds orthogonal and diagonal neighbors, clockwise from Up
+    pub fn neighbors8(&self, index: GridIndex<I>) -> impl Iterator<Item = (GridIndex<I>, &T)> {
+        self.direction_neighbors(index, OctalDirection::iter())
+    }
+
+    fn direction_neighbors<D: AnyDirection<I>>(
+        &self,
+        index: GridIndex<I>,
+        directions: impl Iterator<Item = D>,
+    ) -> impl Iterator<Item = (GridIndex<I>, &T)> {
+        directions.filter_map(move |direction| {
+            let neighbor = self.project_direction(index, direction)?;
+            Some((neighbor, self.get(neighbor)?))
+        })
+    }
+}
+
+/*-----------------------------------------------------------------------------
+  Flood Fill Methods
+-----------------------------------------------------------------------------*/
+
+// A map's connected components: each cell's component ID, and each component's cells
+#[derive(Debug, Clone)]
+pub struct Components<I>
+where
+    I: Integer + Copy + TryInto<usize> + TryFrom<usize>,
+    <I as TryInto<usize>>::Error: std::fmt::Debug,
+    <I as TryFrom<usize>>::Error: std::fmt::Debug,
+{
+    pub labels: GridMap<I, usize>,
+    pub cells: Vec<Vec<GridIndex<I>>>,
+}
+
+impl<I, T> GridMap<I, T>
+where
+    I: Integer + Signed + Copy + TryInto<usize> + TryFrom<usize>,
+    <I as TryInto<usize>>::Error: std::fmt::Debug,
+    <I as TryFrom<usize>>::Error: std::fmt::Debug,
+{
+    // The cells reachable from `start` through orthogonal neighbors, where `can_spread(from, to)`
+    // decides whether the fill spreads between neighboring values. Cells are returned in the order
+    // they were filled, starting with `start`.
+    pub fn flood_fill<F>(&self, start: GridIndex<I>, can_spread: F) -> Vec<GridIndex<I>>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let mut filled = GridMap::new(self.rows(), self.columns(), false);
+        self.fill_from(start, &mut filled, can_spread)
+    }
+
+    // Labels the regions of neighboring cells that are `same_region` as each other (which should be
+    // an equivalence, like equality). Components are numbered in reading order of their first cell.
+    pub fn label_components<F>(&self, mut same_region: F) -> Components<I>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let mut filled = GridMap::new(self.rows(), self.columns(), false);
+        let mut labels = GridMap::new(self.rows(), self.columns(), 0);
+        let mut cells = Vec::new();
+
+        for internal_index in 0..self.data.len() {
+            if filled.data[internal_index] {
+                continue;
+            }
+
+            let start = self.grid_index(internal_index).unwrap();
+            let component = self.fill_from(start, &mut filled, &mut same_region);
+            for &index in &component {
+                labels.set(index, cells.len()).unwrap();
+            }
+            cells.push(component);
+        }
+
+        Components { labels, cells }
+    }
+
+    // Uses an explicit queue, rather than recursion, so large regions can't overflow the stack
+    fn fill_from<F>(
+        &self,
+        start: GridIndex<I>,
+        filled: &mut GridMap<I, bool>,
+        mut can_spread: F,
+    ) -> Vec<GridIndex<I>>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        if filled.set(start, true).is_err() {
+            return Vec::new();
+        }
+
+        let mut component = vec![start];
+        let mut next = 0;
+        while let Some(&index) = component.get(next) {
+            next += 1;
+            let value = self.get(index).unwrap();
+
+            for (neighbor, neighbor_value) in self.neighbors(index) {
+                if !filled.get(neighbor).unwrap() && can_spread(value, neighbor_value) {
+                    filled.set(neighbor, true).unwrap();
+                    component.push(neighbor);
+                }
+            }
+        }
+
+        component
+    }
+}
+
+/*-----------------------------------------------------------------------------
+  Transformation Methods
+-----------------------------------------------------------------------------*/
+
+impl<I, T> GridMap<I, T>
+where
+    I: Integer + Copy + TryInto<usize> + TryFrom<usize>,
+    <I as TryInto<usize>>::Error: std::fmt::Debug,
+    <I as TryFrom<usize>>::Error: std::fmt::Debug,
+    T: Clone,
+{
+    // Rows become columns, mirroring the map across its main (top-left to bottom-right) diagonal
+    pub fn transpose(&self) -> Self {
+        self.transformed(self.columns(), self.rows(), |row, column| (column, row))
+    }
+
+    // Rotates a quarter turn clockwise, so the first row becomes the last column
+    pub fn rotate_cw(&self) -> Self {
+        let rows: usize = self.rows().try_into().unwrap();
+        self.transformed(self.columns(), self.rows(), |row, column| {
+            (rows - 1 - column, row)
+        })
+    }
+
+    // Rotates a quarter turn counterclockwise, so the first row becomes the first column
+    pub fn rotate_ccw(&self) -> Self {
+        let columns: usize = self.columns().try_into().unwrap();
+        self.transformed(self.columns(), self.rows(), |row, column| {
+            (column, columns - 1 - row)
+        })
+    }
+
+    // Mirrors left to right, reversing each row
+    pub fn flip_horizontal(&self) -> Self {
+        let columns: usize = self.columns().try_into().unwrap();
+        self.transformed(self.rows(), self.columns(), |row, column| {
+            (row, columns - 1 - column)
+        })
+    }
+
+    // Mirrors top to bottom, reversing the order of the rows
+    pub fn flip_vertical(&self) -> Self {
+        let rows: usize = self.rows().try_into().unwrap();
+        self.transformed(self.rows(), self.columns(), |row, column| {
+            (rows - 1 - row, column)
+        })
+    }
+
+    // Builds a `rows` x `columns` map, where `source(row, column)` is the position in this map of
+    // each new cell's value. `source` is never called for an empty map, so it can count back from
+    // the last row or column.
+    fn transformed<F>(&self, rows: I, columns: I, source: F) -> Self
+    where
+        F: Fn(usize, usize) -> (usize, usize),
+    {
+        let new_columns: usize = columns.try_into().unwrap();
+        let old_columns: usize = self.columns().try_into().unwrap();
+        let data = (0..self.data.len())
+            .map(|internal_index| {
+                let (row, column) =
+                    source(internal_index / new_columns, internal_index % new_columns);
+                self.data[row * old_columns + column].clone()
+            })
+            .collect();
+
+        Self {
+            data,
+            bounds: GridIndex::new(rows, columns),
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------
+  Grid Window
+-----------------------------------------------------------------------------*/
+
+// A borrowed rectangular part of a map. Window indexes are relative to its top-left cell.
+#[derive(Debug)]
+pub struct GridWindow<'m, I, T>
+where
+    I: Integer + Copy + TryInto<usize> + TryFrom<usize>,
+    <I as TryInto<usize>>::Error: std::fmt::Debug,
+    <I as TryFrom<usize>>::Error: std::fmt::Debug,
+{
+    map: &'m GridMap<I, T>,
+    top_left: GridIndex<I>,
+    size: GridIndex<I>,
+}
+
+// A window only borrows its map, so it can be copied whatever the map's values are (deriving
+// would require `T: Copy`)
+impl<I, T> Clone for GridWindow<'_, I, T>
+where
+    I: Integer + Copy + TryInto<usize> + TryFrom<usize>,
+    <I as TryInto<usize>>::Error: std::fmt::Debug,
+    <I as TryFrom<usize>>::Error: std::fmt::Debug,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I, T> Copy for GridWindow<'_, I, T>
+where
+    I: Integer + Copy + TryInto<usize> + TryFrom<usize>,
+    <I as TryInto<usize>>::Error: std::fmt::Debug,
+    <I as TryFrom<usize>>::Error: std::fmt::Debug,
+{
+}
+
+impl<I, T> GridMap<I, T>
+where
+    I: Integer + Copy + TryInto<usize> + TryFrom<usize>,
+    <I as TryInto<usize>>::Error: std::fmt::Debug,
+    <I as TryFrom<usize>>::Error: std::fmt::Debug,
+{
+    // The `size.row` x `size.column` window from `top_left`, if it fits within the map
+    pub fn window(
+        &self,
+        top_left: GridIndex<I>,
+        size: GridIndex<I>,
+    ) -> Option<GridWindow<'_, I, T>> {
+        // Empty windows can start on the map's right or bottom edge
+        let bottom_right = top_left + size;
+        let fits = I::zero() <= top_left.row
+            && I::zero() <= top_left.column
+            && I::zero() <= size.row
+            && I::zero() <= size.column
+            && bottom_right.row <= self.bounds.row
+            && bottom_right.column <= self.bounds.column;
+
+        fits.then_some(GridWindow {
+            map: self,
+            top_left,
+            size,
+        })
+    }
+}
+
+impl<'m, I, T> GridWindow<'m, I, T>
+where
+    I: Integer + Copy + TryInto<usize> + TryFrom<usize>,
+    <I as TryInto<usize>>::Error: std::fmt::Debug,
+    <I as TryFrom<usize>>::Error: std::fmt::Debug,
+{
+    pub fn rows(&self) -> I {
+        self.size.row
+    }
+
+    pub fn columns(&self) -> I {
+        self.size.column
+    }
+
+    // The window's top-left cell, as an index in the map
+    pub fn top_left(&self) -> GridIndex<I> {
+        self.top_left
+    }
+
+    pub fn get(&self, index: GridIndex<I>) -> Option<&'m T> {
+        let in_window = (I::zero()..self.size.row).contains(&index.row)
+            && (I::zero()..self.size.column).contains(&index.column);
+        in_window
+            .then(|| self.map.get(self.top_left + index))
+            .flatten()
+    }
+
+    pub fn rows_iter(&self) -> impl Iterator<Item = &'m [T]> + 'm {
+        let map = self.map;
+        let map_columns: usize = map.columns().try_into().unwrap();
+        let top: usize = self.top_left.row.try_into().unwrap();
+        let left: usize = self.top_left.column.try_into().unwrap();
+        let rows: usize = self.size.row.try_into().unwrap();
+        let columns: usize = self.size.column.try_into().unwrap();
+
+        (top..top + rows).map(move |row| {
+            let start = row * map_columns + left;
+            &map.data[start..start + columns]
+        })
+    }
+
+    pub fn columns_iter(&self) -> impl Iterator<Item = impl Iterator<Item = &'m T>> + 'm {
+        let window = *self;
+        let columns: usize = self.size.column.try_into().unwrap();
+        (0..columns).map(move |column| window.rows_iter().map(move |row| &row[column]))
+    }
+
+    // Copies the window into a map of its own
+    pub fn to_grid_map(&self) -> GridMap<I, T>
+    where
+        T: Clone,
+    {
+        GridMap {
+            data: self.rows_iter().flatten().cloned().collect(),
+            bounds: self.size,
+        }
+    }
 }
 
 /*-----------------------------------------------------------------------------
@@ -379,3 +711,161 @@ where
             .join("\n")
     }
 }
+
+/*-------------------------------------------------------------------------------------------------
+  Tests
+-------------------------------------------------------------------------------------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "abc\ndef\nghi";
+
+    fn values<'a>(neighbors: impl Iterator<Item = (GridIndex<i32>, &'a char)>) -> String {
+        neighbors.map(|(_, value)| *value).collect()
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let map: GridMap<i32, char> = MAP.into();
+
+        assert_eq!(values(map.neighbors(GridIndex::new(1, 1))), "bhdf");
+        assert_eq!(values(map.neighbors(GridIndex::new(0, 0))), "db");
+        assert_eq!(
+            map.neighbors(GridIndex::new(2, 2)).collect::<Vec<_>>(),
+            [(GridIndex::new(1, 2), &'f'), (GridIndex::new(2, 1), &'h')]
+        );
+    }
+
+    #[test]
+    fn test_neighbors8() {
+        let map: GridMap<i32, char> = MAP.into();
+
+        assert_eq!(values(map.neighbors8(GridIndex::new(1, 1))), "bcfihgda");
+        assert_eq!(values(map.neighbors8(GridIndex::new(0, 2))), "feb");
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let map: GridMap<i32, char> = "aab\nbab\nbbb".into();
+
+        let mut filled = map.flood_fill(GridIndex::new(0, 0), |from, to| from == to);
+        filled.sort();
+        assert_eq!(filled, [(0, 0), (0, 1), (1, 1)].map(GridIndex::from));
+        assert_eq!(map.flood_fill(GridIndex::new(0, 2), |_, _| true).len(), 9);
+        assert!(map.flood_fill(GridIndex::new(3, 0), |_, _| true).is_empty());
+    }
+
+    #[test]
+    fn test_label_components() {
+        let map: GridMap<i32, char> = "aab\nbab\nbba".into();
+
+        let components = map.label_components(|a, b| a == b);
+        assert_eq!(components.cells.len(), 4);
+        assert_eq!(
+            components.labels.rows_iter().collect::<Vec<_>>(),
+            [[0, 0, 1], [2, 0, 1], [2, 2, 3]]
+        );
+        assert_eq!(components.cells[2].len(), 3);
+
+        // Large regions are filled without recursion
+        let map: GridMap<i32, char> = GridMap::new(250, 250, 'a');
+        assert_eq!(map.label_components(|a, b| a == b).cells[0].len(), 62_500);
+    }
+
+    fn text(map: &GridMap<i32, char>) -> String {
+        map.clone().into()
+    }
+
+    #[test]
+    fn test_transformations() {
+        let map: GridMap<i32, char> = "abc\ndef".into();
+
+        assert_eq!(text(&map.transpose()), "ad\nbe\ncf");
+        assert_eq!(text(&map.rotate_cw()), "da\neb\nfc");
+        assert_eq!(text(&map.rotate_ccw()), "cf\nbe\nad");
+        assert_eq!(text(&map.flip_horizontal()), "cba\nfed");
+        assert_eq!(text(&map.flip_vertical()), "def\nabc");
+        assert_eq!(
+            text(&map.rotate_cw().rotate_cw()),
+            text(&map.flip_horizontal().flip_vertical())
+        );
+        assert_eq!(map.transpose().get(GridIndex::new(2, 1)), Some(&'f'));
+    }
+
+    #[test]
+    fn test_transform_empty_map() {
+        let map: GridMap<usize, char> = GridMap::new(0, 3, '.');
+
+        for transformed in [
+            map.rotate_cw(),
+            map.rotate_ccw(),
+            map.flip_horizontal(),
+            map.flip_vertical(),
+        ] {
+            assert!(transformed.is_empty());
+        }
+        assert_eq!((map.rotate_cw().rows(), map.rotate_cw().columns()), (3, 0));
+    }
+
+    #[test]
+    fn test_window() {
+        let map: GridMap<i32, char> = MAP.into();
+
+        let window = map
+            .window(GridIndex::new(1, 1), GridIndex::new(2, 2))
+            .unwrap();
+        assert_eq!((window.rows(), window.columns()), (2, 2));
+        assert_eq!(window.get(GridIndex::new(0, 1)), Some(&'f'));
+        assert_eq!(window.get(GridIndex::new(0, 2)), None);
+        assert_eq!(
+            window.rows_iter().collect::<Vec<_>>(),
+            [['e', 'f'], ['h', 'i']]
+        );
+        let columns: Vec<String> = window
+            .columns_iter()
+            .map(|column| column.collect())
+            .collect();
+        assert_eq!(columns, ["eh", "fi"]);
+        assert_eq!(text(&window.to_grid_map()), "ef\nhi");
+
+        assert!(map
+            .window(GridIndex::new(2, 2), GridIndex::new(1, 1))
+            .is_some());
+        assert!(map
+            .window(GridIndex::new(2, 2), GridIndex::new(2, 1))
+            .is_none());
+        assert!(map
+            .window(GridIndex::new(-1, 0), GridIndex::new(1, 1))
+            .is_none());
+
+        let empty = map
+            .window(GridIndex::new(1, 3), GridIndex::new(2, 0))
+            .unwrap();
+        assert_eq!(empty.columns_iter().count(), 0);
+        assert_eq!(empty.rows_iter().filter(|row| row.is_empty()).count(), 2);
+
+        let columns: Vec<String> = map.columns_iter().map(|column| column.collect()).collect();
+        assert_eq!(columns, ["adg", "beh", "cfi"]);
+    }
+
+    #[test]
+    fn test_neighbors_with() {
+        let map: GridMap<i32, char> = MAP.into();
+        let knight_moves = [
+            GridIndex::new(2, 1),
+            GridIndex::new(1, 2),
+            GridIndex::new(-1, 2),
+        ];
+
+        assert_eq!(
+            values(map.neighbors_with(GridIndex::new(0, 0), &knight_moves)),
+            "hf"
+        );
+        assert_eq!(
+            values(map.neighbors_with(GridIndex::new(1, 0), &knight_moves)),
+            "ic"
+        );
+    }
+}
@@ -0,0 +1,216 @@
+use crate::shared::grid_directions::{AnyDirection, GridDirection};
+use crate::shared::grid_index::GridIndex;
+use num::{Integer, Signed};
+use std::collections::HashSet;
+use std::hash::Hash;
+use strum::IntoEnumIterator;
+
+/*-------------------------------------------------------------------------------------------------
+  Region
+-------------------------------------------------------------------------------------------------*/
+
+// A set of grid cells, measured as the shape they cover. Cells are neighbors when they share an
+// edge, so cells that only touch diagonally are separate parts of the shape's outline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridRegion<I>
+where
+    I: Integer + Signed + Copy + Hash,
+{
+    cells: HashSet<GridIndex<I>>,
+}
+
+impl<I> GridRegion<I>
+where
+    I: Integer + Signed + Copy + Hash,
+{
+    pub fn new(cells: impl IntoIterator<Item = GridIndex<I>>) -> Self {
+        Self {
+            cells: cells.into_iter().collect(),
+        }
+    }
+
+    pub fn cells(&self) -> &HashSet<GridIndex<I>> {
+        &self.cells
+    }
+
+    pub fn contains(&self, index: GridIndex<I>) -> bool {
+        self.cells.contains(&index)
+    }
+
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    // The number of cell edges on the region's outline, including the outlines of any holes
+    pub fn perimeter(&self) -> usize {
+        self.cells
+            .iter()
+            .flat_map(|&cell| {
+                GridDirection::iter()
+                    .filter(move |direction| !self.contains(cell + direction.offset()))
+            })
+            .count()
+    }
+
+    // The number of straight sides on the region's outline (including holes), which is the same as
+    // the number of corners. Each cell has a corner where two turning directions are both outside
+    // the region (convex), or both inside with the cell between them outside (concave).
+    pub fn sides(&self) -> usize {
+        self.cells
+            .iter()
+            .flat_map(|&cell| {
+                GridDirection::iter().filter(move |&direction| {
+                    let turned = direction.turn_clockwise();
+                    let ahead = self.contains(cell + direction.offset());
+                    let beside = self.contains(cell + turned.offset());
+                    let diagonal = self.contains(cell + direction.offset() + turned.offset());
+
+                    (!ahead && !beside) || (ahead && beside && !diagonal)
+                })
+            })
+            .count()
+    }
+
+    // The smallest and largest row and column, as the box's top-left and bottom-right cells
+    pub fn bounding_box(&self) -> Option<(GridIndex<I>, GridIndex<I>)> {
+        let mut cells = self.cells.iter();
+        let first = *cells.next()?;
+
+        Some(cells.fold((first, first), |(min, max), cell| {
+            (
+                GridIndex::new(min.row.min(cell.row), min.column.min(cell.column)),
+                GridIndex::new(max.row.max(cell.row), max.column.max(cell.column)),
+            )
+        }))
+    }
+
+    // The areas outside the region that are completely enclosed by it
+    pub fn holes(&self) -> Vec<GridRegion<I>> {
+        let Some((min, max)) = self.bounding_box() else {
+            return Vec::new();
+        };
+        let in_box = |cell: GridIndex<I>| {
+            min.row <= cell.row
+                && cell.row <= max.row
+                && min.column <= cell.column
+                && cell.column <= max.column
+        };
+
+        let mut visited: HashSet<GridIndex<I>> = HashSet::new();
+        let mut holes = Vec::new();
+
+        for start in box_cells(min, max) {
+            if self.contains(start) || visited.contains(&start) {
+                continue;
+            }
+
+            // Fill the outside area, noting whether it escapes the bounding box
+            let mut area = vec![start];
+            let mut enclosed = true;
+            visited.insert(start);
+            let mut next = 0;
+            while let Some(&cell) = area.get(next) {
+                next += 1;
+                for direction in GridDirection::iter() {
+                    let neighbor = cell + direction.offset();
+                    if !in_box(neighbor) {
+                        enclosed = false;
+                    } else if !self.contains(neighbor) && visited.insert(neighbor) {
+                        area.push(neighbor);
+                    }
+                }
+            }
+
+            if enclosed {
+                holes.push(GridRegion::new(area));
+            }
+        }
+
+        holes
+    }
+}
+
+// The cells of a bounding box, in reading order
+fn box_cells<I>(min: GridIndex<I>, max: GridIndex<I>) -> impl Iterator<Item = GridIndex<I>>
+where
+    I: Integer + Copy,
+{
+    let rows = std::iter::successors(Some(min.row), move |&row| {
+        (row < max.row).then(|| row + I::one())
+    });
+    rows.flat_map(move |row| {
+        std::iter::successors(Some(min.column), move |&column| {
+            (column < max.column).then(|| column + I::one())
+        })
+        .map(move |column| GridIndex::new(row, column))
+    })
+}
+
+/*-------------------------------------------------------------------------------------------------
+  Tests
+-------------------------------------------------------------------------------------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::grid_map::GridMap;
+
+    fn regions(map: &str) -> Vec<(char, GridRegion<i32>)> {
+        let map: GridMap<i32, char> = map.into();
+        map.label_components(|a, b| a == b)
+            .cells
+            .into_iter()
+            .map(|cells| (*map.get(cells[0]).unwrap(), GridRegion::new(cells)))
+            .collect()
+    }
+
+    #[test]
+    fn test_ring() {
+        let ring = GridRegion::new(
+            [
+                (0, 0),
+                (0, 1),
+                (0, 2),
+                (1, 0),
+                (1, 2),
+                (2, 0),
+                (2, 1),
+                (2, 2),
+            ]
+            .map(GridIndex::from),
+        );
+
+        assert_eq!(ring.area(), 8);
+        assert_eq!(ring.perimeter(), 16);
+        assert_eq!(ring.sides(), 8);
+        assert_eq!(
+            ring.bounding_box(),
+            Some((GridIndex::new(0, 0), GridIndex::new(2, 2)))
+        );
+        assert_eq!(ring.holes(), [GridRegion::new([GridIndex::new(1, 1)])]);
+    }
+
+    #[test]
+    fn test_sides() {
+        let (_, e) = &regions("EEEEE\nEXXXX\nEEEEE\nEXXXX\nEEEEE")[0];
+        assert_eq!((e.area(), e.sides()), (17, 12));
+        assert!(e.holes().is_empty());
+
+        // The B regions touch diagonally, so the A region's inner outline has separate sides
+        let a_and_b = regions("AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA");
+        let summary: Vec<_> = a_and_b
+            .iter()
+            .map(|(plant, region)| (*plant, region.area(), region.sides()))
+            .collect();
+        assert_eq!(summary, [('A', 28, 12), ('B', 4, 4), ('B', 4, 4)]);
+        assert_eq!(a_and_b[0].1.holes().len(), 2);
+    }
+
+    #[test]
+    fn test_empty_region() {
+        let empty: GridRegion<i32> = GridRegion::new([]);
+        assert_eq!((empty.area(), empty.perimeter(), empty.sides()), (0, 0, 0));
+        assert_eq!(empty.bounding_box(), None);
+        assert!(empty.holes().is_empty());
+    }
+}
@@ -0,0 +1,54 @@
+use regex::Regex;
+use std::sync::OnceLock;
+
+/*-------------------------------------------------------------------------------------------------
+  Puzzle Page HTML
+-------------------------------------------------------------------------------------------------*/
+
+// Just enough HTML handling for the puzzle site's pages, which keep each puzzle part and each
+// response message in its own `<article>`
+
+static ARTICLE_REGEX_CELL: OnceLock<Regex> = OnceLock::new();
+static TAG_REGEX_CELL: OnceLock<Regex> = OnceLock::new();
+
+pub fn articles(html: &str) -> Vec<&str> {
+    ARTICLE_REGEX_CELL
+        .get_or_init(|| Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap())
+        .captures_iter(html)
+        .map(|captures| captures.get(1).unwrap().as_str())
+        .collect()
+}
+
+// Removes the markup and decodes the entities, preserving the whitespace
+pub fn text(html: &str) -> String {
+    let text = TAG_REGEX_CELL
+        .get_or_init(|| Regex::new(r"<[^>]*>").unwrap())
+        .replace_all(html, "");
+
+    // `&amp;` is decoded last, so escaped entities (like `&amp;lt;`) stay escaped
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/*-------------------------------------------------------------------------------------------------
+  Tests
+-------------------------------------------------------------------------------------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text() {
+        let html = "<main><article><p>A <em>bold</em> &lt;move&gt; &amp;lt;</p></article>\
+                    <article>Two</article></main>";
+        assert_eq!(
+            articles(html),
+            ["<p>A <em>bold</em> &lt;move&gt; &amp;lt;</p>", "Two"]
+        );
+        assert_eq!(text(articles(html)[0]), "A bold <move> &lt;");
+    }
+}
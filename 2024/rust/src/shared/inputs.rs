@@ -1,9 +1,98 @@
+use crate::shared::data_dir::locate_data_file;
+use anyhow::{Context, Result};
+use std::fmt::{Display, Formatter};
 use std::fs::read_to_string;
+use std::io::Read;
+use std::path::{Path, PathBuf};
 
 /*-------------------------------------------------------------------------------------------------
   Inputs
 -------------------------------------------------------------------------------------------------*/
 
-pub fn get_input<P: AsRef<std::path::Path> + ?Sized>(file_path: &P) -> String {
-    read_to_string(file_path).unwrap()
+pub fn get_input<P: AsRef<Path> + ?Sized>(file_path: &P) -> Result<String> {
+    let file_path = locate_data_file(file_path.as_ref());
+    read_to_string(&file_path).with_context(|| format!("Failed to read input file {file_path:?}"))
+}
+
+/*--------------------------------------------------------------------------------------
+  Input Source
+--------------------------------------------------------------------------------------*/
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    Inline(String),
+}
+
+impl InputSource {
+    pub fn read(&self) -> Result<String> {
+        match self {
+            Self::File(path) => get_input(path),
+            Self::Stdin => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .context("Failed to read input from stdin")?;
+                Ok(input)
+            }
+            Self::Inline(text) => Ok(text.clone()),
+        }
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            Self::File(path) => Some(path),
+            _ => None,
+        }
+    }
+}
+
+impl From<PathBuf> for InputSource {
+    fn from(path: PathBuf) -> Self {
+        if path.as_os_str() == "-" {
+            Self::Stdin
+        } else {
+            Self::File(path)
+        }
+    }
+}
+
+impl From<&Path> for InputSource {
+    fn from(path: &Path) -> Self {
+        path.to_path_buf().into()
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Stdin => write!(f, "-"),
+            Self::Inline(_) => write!(f, "<inline>"),
+        }
+    }
+}
+
+/*-------------------------------------------------------------------------------------------------
+  Tests
+-------------------------------------------------------------------------------------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_source() {
+        assert_eq!(InputSource::from(PathBuf::from("-")), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from(PathBuf::from("input.txt")),
+            InputSource::File(PathBuf::from("input.txt"))
+        );
+
+        let inline = InputSource::Inline("3   4\n4   3".to_string());
+        assert_eq!(inline.read().unwrap(), "3   4\n4   3");
+        assert_eq!(inline.path(), None);
+        assert_eq!(inline.to_string(), "<inline>");
+    }
 }
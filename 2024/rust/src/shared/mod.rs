@@ -2,8 +2,28 @@
   Shared Modules
 -------------------------------------------------------------------------------------------------*/
 
+pub mod allocations;
+pub mod answer_files;
 pub mod answers;
+pub mod bench_report;
+pub mod budgets;
+pub mod cli;
+pub mod client;
+pub mod data_dir;
+#[cfg(test)]
+mod data_tests;
+pub mod examples;
 pub mod grid_directions;
 pub mod grid_index;
 pub mod grid_map;
+pub mod grid_region;
+mod html;
 pub mod inputs;
+pub mod output;
+pub mod parsing;
+pub mod profile;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod solution;
+pub mod watch;
@@ -0,0 +1,172 @@
+use crate::shared::allocations::AllocationStats;
+use crate::shared::runner::{RunResult, Status};
+use serde::Serialize;
+
+/*-------------------------------------------------------------------------------------------------
+  Output Formats
+-------------------------------------------------------------------------------------------------*/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+/*--------------------------------------------------------------------------------------
+  Records
+--------------------------------------------------------------------------------------*/
+
+#[derive(Debug, Serialize)]
+struct Record<'r> {
+    day: u8,
+    part: String,
+    input: String,
+    answer: Option<String>,
+    expected: Option<&'r str>,
+    error: Option<&'r str>,
+    status: Status,
+    parse_ms: f64,
+    solve_ms: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_allocations: Option<AllocationStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    solve_allocations: Option<AllocationStats>,
+}
+
+impl<'r> From<&'r RunResult> for Record<'r> {
+    fn from(result: &'r RunResult) -> Self {
+        Self {
+            day: result.day,
+            part: result.part.to_string(),
+            input: result.input.to_string(),
+            answer: result.answer.as_ref().map(ToString::to_string),
+            expected: result.expected.as_deref(),
+            error: result.error.as_deref(),
+            status: result.status(),
+            parse_ms: result.parse_duration.as_secs_f64() * 1000.0,
+            solve_ms: result.solve_duration.as_secs_f64() * 1000.0,
+            parse_allocations: result.parse_allocations,
+            solve_allocations: result.solve_allocations,
+        }
+    }
+}
+
+/*--------------------------------------------------------------------------------------
+  Formatters
+--------------------------------------------------------------------------------------*/
+
+pub fn json_records(results: &[RunResult]) -> String {
+    let records: Vec<Record> = results.iter().map(Record::from).collect();
+    serde_json::to_string_pretty(&records).unwrap()
+}
+
+pub fn csv_records(results: &[RunResult]) -> String {
+    let header = "day,part,input,answer,expected,error,status,parse_ms,solve_ms".to_string();
+    let rows = results.iter().map(Record::from).map(|record| {
+        [
+            record.day.to_string(),
+            record.part,
+            csv_field(&record.input),
+            csv_field(&record.answer.unwrap_or_default()),
+            csv_field(record.expected.unwrap_or_default()),
+            csv_field(record.error.unwrap_or_default()),
+            record.status.as_str().to_string(),
+            record.parse_ms.to_string(),
+            record.solve_ms.to_string(),
+        ]
+        .join(",")
+    });
+
+    std::iter::once(header)
+        .chain(rows)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/*-------------------------------------------------------------------------------------------------
+  Tests
+-------------------------------------------------------------------------------------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::answers::Answer;
+    use crate::shared::inputs::InputSource;
+    use crate::shared::solution::Part;
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    fn results() -> Vec<RunResult> {
+        vec![RunResult {
+            day: 23,
+            part: Part::Part2,
+            input: InputSource::File(PathBuf::from("../data/day23/input.txt")),
+            answer: Some(Answer::List(vec![
+                "aa".to_string(),
+                "bb".to_string(),
+                "cc".to_string(),
+            ])),
+            expected: None,
+            error: None,
+            parse_duration: Duration::from_micros(250),
+            solve_duration: Duration::from_micros(1500),
+            over_budget: false,
+            parse_allocations: None,
+            solve_allocations: None,
+            parse_spans: Vec::new(),
+            solve_spans: Vec::new(),
+        }]
+    }
+
+    #[test]
+    fn test_json_records() {
+        let json: serde_json::Value = serde_json::from_str(&json_records(&results())).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([{
+                "day": 23,
+                "part": "part2",
+                "input": "../data/day23/input.txt",
+                "answer": "aa,bb,cc",
+                "expected": null,
+                "error": null,
+                "status": "unverified",
+                "parse_ms": 0.25,
+                "solve_ms": 1.5,
+            }])
+        );
+
+        // Allocations are only included when they were counted
+        let mut results = results();
+        results[0].solve_allocations = Some(AllocationStats {
+            allocations: 2,
+            allocated_bytes: 96,
+            peak_bytes: 64,
+        });
+        let json: serde_json::Value = serde_json::from_str(&json_records(&results)).unwrap();
+        assert_eq!(
+            json[0]["solve_allocations"],
+            serde_json::json!({"allocations": 2, "allocated_bytes": 96, "peak_bytes": 64})
+        );
+        assert!(json[0].get("parse_allocations").is_none());
+    }
+
+    #[test]
+    fn test_csv_records() {
+        assert_eq!(
+            csv_records(&results()),
+            "day,part,input,answer,expected,error,status,parse_ms,solve_ms\n\
+             23,part2,../data/day23/input.txt,\"aa,bb,cc\",,,unverified,0.25,1.5"
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use crate::shared::solution::Day;
+use anyhow::Result;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/*-------------------------------------------------------------------------------------------------
+  Input Parsing
+-------------------------------------------------------------------------------------------------*/
+
+/*--------------------------------------------------------------------------------------
+  Parse Error
+--------------------------------------------------------------------------------------*/
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: Day,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub reason: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Day {}, line {}, column {}: {}",
+            self.day, self.line, self.column, self.reason
+        )?;
+
+        if !self.text.is_empty() {
+            write!(f, " ({:?})", self.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/*--------------------------------------------------------------------------------------
+  Input Parser
+--------------------------------------------------------------------------------------*/
+
+// Locates parse errors in the puzzle input; the `text` passed to these methods must be a slice
+// of the input so that its line and column can be recovered.
+
+#[derive(Debug, Clone, Copy)]
+pub struct InputParser<'i> {
+    day: Day,
+    input: &'i str,
+}
+
+impl<'i> InputParser<'i> {
+    pub fn new(day: Day, input: &'i str) -> Self {
+        Self { day, input }
+    }
+
+    pub fn parse<T>(&self, text: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        <T as FromStr>::Err: Display,
+    {
+        text.parse().map_err(|error| self.error(text, error))
+    }
+
+    pub fn required<'t>(&self, token: Option<&'t str>, line: &str) -> Result<&'t str, ParseError> {
+        token.ok_or_else(|| self.error(line, "Missing value"))
+    }
+
+    pub fn first_line(&self) -> Result<&'i str, ParseError> {
+        self.input
+            .lines()
+            .next()
+            .ok_or_else(|| self.error_at_end("Input is empty"))
+    }
+
+    pub fn rectangular(&self, section: &str) -> Result<(), ParseError> {
+        let columns = section
+            .lines()
+            .next()
+            .ok_or_else(|| self.error(section, "Missing grid"))?
+            .chars()
+            .count();
+
+        match section.lines().find(|line| line.chars().count() != columns) {
+            Some(line) => Err(self.error(
+                line,
+                format!("Expected {columns} columns, found {}", line.chars().count()),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    pub fn char_error(&self, line: &str, index: usize, reason: impl Display) -> ParseError {
+        let length = line[index..].chars().next().map_or(0, char::len_utf8);
+        self.error(&line[index..index + length], reason)
+    }
+
+    pub fn error_at_end(&self, reason: impl Display) -> ParseError {
+        self.error(&self.input[self.input.len()..], reason)
+    }
+
+    pub fn error(&self, text: &str, reason: impl Display) -> ParseError {
+        let (line, column) = self.locate(text).unwrap_or((0, 0));
+
+        ParseError {
+            day: self.day,
+            line,
+            column,
+            text: text.lines().next().unwrap_or_default().to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    fn locate(&self, text: &str) -> Option<(usize, usize)> {
+        let start = self.input.as_ptr() as usize;
+        let position = text.as_ptr() as usize;
+
+        if !(start..=start + self.input.len()).contains(&position) {
+            return None;
+        }
+
+        let preceding = &self.input[..position - start];
+        let line_start = preceding.rfind('\n').map_or(0, |index| index + 1);
+        let line = preceding.matches('\n').count() + 1;
+        let column = preceding[line_start..].chars().count() + 1;
+
+        Some((line, column))
+    }
+}
+
+/*-------------------------------------------------------------------------------------------------
+  Tests
+-------------------------------------------------------------------------------------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let input = "12 34\n56 x8\n";
+        let parser = InputParser::new(1, input);
+
+        assert_eq!(parser.parse::<u8>(&input[3..5]), Ok(34));
+
+        let error = parser.parse::<u8>(&input[9..11]).unwrap_err();
+        assert_eq!(error.day, 1);
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 4);
+        assert_eq!(error.text, "x8");
+        assert_eq!(
+            error.to_string(),
+            "Day 1, line 2, column 4: invalid digit found in string (\"x8\")"
+        );
+    }
+
+    #[test]
+    fn test_required() {
+        let input = "1|2\n3\n";
+        let parser = InputParser::new(5, input);
+        let line = input.lines().nth(1).unwrap();
+
+        let error = parser.required(line.split('|').nth(1), line).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.reason, "Missing value");
+    }
+
+    #[test]
+    fn test_rectangular() {
+        let input = "ab\ncd\n";
+        assert!(InputParser::new(4, input).rectangular(input).is_ok());
+
+        let input = "ab\ncd\n\nef\n";
+        let error = InputParser::new(4, input).rectangular(input).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.reason, "Expected 2 columns, found 0");
+    }
+
+    #[test]
+    fn test_error_at_end() {
+        let error = InputParser::new(9, "").first_line().unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.to_string(), "Day 9, line 1, column 1: Input is empty");
+    }
+}
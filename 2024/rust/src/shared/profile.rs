@@ -0,0 +1,243 @@
+use crate::shared::runner::RunResult;
+use std::cell::RefCell;
+use std::fmt::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+/*-------------------------------------------------------------------------------------------------
+  Profiling Spans
+-------------------------------------------------------------------------------------------------*/
+
+// Solutions mark their phases with spans, which time themselves until they are dropped:
+//
+//     let course = {
+//         let _span = span("map_course");
+//         map_course(map)
+//     };
+//
+// Spans do nothing unless profiling is enabled (with `--profile` or `--debug`). The runner collects
+// the spans opened while parsing and solving, and spans with the same name and parent are combined.
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    // The spans being timed on this thread, outermost first, while spans are collected
+    static OPEN_SPANS: RefCell<Vec<SpanTiming>> = const { RefCell::new(Vec::new()) };
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpanTiming {
+    pub name: &'static str,
+    pub calls: u32,
+    pub duration: Duration,
+    pub children: Vec<SpanTiming>,
+}
+
+impl SpanTiming {
+    fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            calls: 0,
+            duration: Duration::ZERO,
+            children: Vec::new(),
+        }
+    }
+}
+
+#[must_use = "a span times until it is dropped"]
+pub struct Span {
+    start: Option<Instant>,
+}
+
+pub fn enable_profiling() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+#[inline]
+pub fn span(name: &'static str) -> Span {
+    if ENABLED.load(Ordering::Relaxed) {
+        Span::open(name)
+    } else {
+        Span { start: None }
+    }
+}
+
+impl Span {
+    // Spans opened outside of `profile_spans` aren't timed
+    fn open(name: &'static str) -> Self {
+        let collecting = OPEN_SPANS.with_borrow_mut(|open_spans| {
+            if open_spans.is_empty() {
+                return false;
+            }
+            open_spans.push(SpanTiming::new(name));
+            true
+        });
+
+        Self {
+            start: collecting.then(Instant::now),
+        }
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        let Some(start) = self.start else {
+            return;
+        };
+        let duration = start.elapsed();
+
+        OPEN_SPANS.with_borrow_mut(|open_spans| {
+            let Some(mut timing) = open_spans.pop() else {
+                return;
+            };
+            timing.calls += 1;
+            timing.duration += duration;
+
+            if let Some(parent) = open_spans.last_mut() {
+                merge_span(&mut parent.children, timing);
+            }
+        });
+    }
+}
+
+fn merge_span(spans: &mut Vec<SpanTiming>, timing: SpanTiming) {
+    let Some(existing) = spans.iter_mut().find(|span| span.name == timing.name) else {
+        spans.push(timing);
+        return;
+    };
+
+    existing.calls += timing.calls;
+    existing.duration += timing.duration;
+    for child in timing.children {
+        merge_span(&mut existing.children, child);
+    }
+}
+
+// Collects the spans `f` opens on this thread; nothing is collected unless profiling is enabled
+pub fn profile_spans<T>(f: impl FnOnce() -> T) -> (T, Vec<SpanTiming>) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return (f(), Vec::new());
+    }
+
+    collect_spans(f)
+}
+
+fn collect_spans<T>(f: impl FnOnce() -> T) -> (T, Vec<SpanTiming>) {
+    let outer_spans = OPEN_SPANS.replace(vec![SpanTiming::new("")]);
+    let result = f();
+    let spans = OPEN_SPANS.replace(outer_spans);
+
+    let root = spans
+        .into_iter()
+        .next()
+        .unwrap_or_else(|| SpanTiming::new(""));
+    (result, root.children)
+}
+
+/*--------------------------------------------------------------------------------------
+  Profile Tree
+--------------------------------------------------------------------------------------*/
+
+// Each result's parse and solve times, with the spans opened during each, like:
+//
+//     Day 20 part2          19.24ms
+//       parse               76.41µs
+//       solve               19.16ms
+//         map_course        45.03µs
+//         find_cheats       18.31ms
+//
+// Spans combined from several calls show the number of calls.
+pub fn profile_tree(results: &[RunResult]) -> String {
+    let mut lines: Vec<(String, Duration, u32)> = Vec::new();
+
+    for result in results {
+        lines.push((
+            format!("Day {} {}", result.day, result.part),
+            result.parse_duration + result.solve_duration,
+            1,
+        ));
+        for (name, duration, spans) in [
+            ("parse", result.parse_duration, &result.parse_spans),
+            ("solve", result.solve_duration, &result.solve_spans),
+        ] {
+            lines.push((format!("  {name}"), duration, 1));
+            add_span_lines(&mut lines, spans, 2);
+        }
+    }
+
+    let width = lines
+        .iter()
+        .map(|(name, _, _)| name.chars().count())
+        .max()
+        .unwrap_or_default();
+
+    let mut tree = String::new();
+    for (name, duration, calls) in lines {
+        let duration = format!("{duration:.2?}");
+        write!(tree, "{name:<width$}  {duration:>10}").unwrap();
+        if calls > 1 {
+            write!(tree, "  ({calls} calls)").unwrap();
+        }
+        tree.push('\n');
+    }
+
+    tree
+}
+
+fn add_span_lines(lines: &mut Vec<(String, Duration, u32)>, spans: &[SpanTiming], depth: usize) {
+    for span in spans {
+        lines.push((
+            format!("{}{}", "  ".repeat(depth), span.name),
+            span.duration,
+            span.calls,
+        ));
+        add_span_lines(lines, &span.children, depth + 1);
+    }
+}
+
+/*-------------------------------------------------------------------------------------------------
+  Tests
+-------------------------------------------------------------------------------------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(spans: &[SpanTiming]) -> Vec<(&'static str, u32, Vec<&'static str>)> {
+        spans
+            .iter()
+            .map(|span| {
+                let children = span.children.iter().map(|child| child.name).collect();
+                (span.name, span.calls, children)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_collect_spans() {
+        // Profiling isn't enabled for the tests, so spans are opened directly
+        let (answer, spans) = collect_spans(|| {
+            let build = Span::open("build");
+            for _ in 0..3 {
+                let _search = Span::open("search");
+                let _step = Span::open("step");
+            }
+            drop(build);
+
+            let _aggregate = Span::open("aggregate");
+            42
+        });
+
+        assert_eq!(answer, 42);
+        assert_eq!(
+            names(&spans),
+            [("build", 1, vec!["search"]), ("aggregate", 1, vec![]),]
+        );
+        assert_eq!(names(&spans[0].children), [("search", 3, vec!["step"])]);
+        assert!(spans[0].duration >= spans[0].children[0].duration);
+
+        // Spans aren't timed outside of a collection
+        assert!(Span::open("outside").start.is_none());
+        assert!(span("disabled").start.is_none());
+    }
+}
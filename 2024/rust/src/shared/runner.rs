@@ -0,0 +1,856 @@
+use crate::shared::allocations::{count_allocations, format_bytes, AllocationStats};
+use crate::shared::answers::{get_answer, Answer};
+use crate::shared::budgets::{Budget, Budgets};
+use crate::shared::data_dir::locate_data_file;
+use crate::shared::inputs::InputSource;
+use crate::shared::profile::{profile_spans, SpanTiming};
+use crate::shared::solution::{Day, Parameters, ParsedInput, Part, Solution};
+use anyhow::{anyhow, bail, Context, Result};
+use itertools::Itertools;
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::{mpsc, Arc, Condvar, Mutex, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/*-------------------------------------------------------------------------------------------------
+  Solution Runner
+-------------------------------------------------------------------------------------------------*/
+
+#[derive(Debug, Clone)]
+pub struct RunResult {
+    pub day: Day,
+    pub part: Part,
+    pub input: InputSource,
+    pub answer: Option<Answer>,
+    pub expected: Option<String>,
+    pub error: Option<String>,
+    pub parse_duration: Duration,
+    pub solve_duration: Duration,
+    pub over_budget: bool,
+    // Only counted when the binary is built with the `count_allocations` feature
+    pub parse_allocations: Option<AllocationStats>,
+    pub solve_allocations: Option<AllocationStats>,
+    // Only collected when profiling is enabled
+    pub parse_spans: Vec<SpanTiming>,
+    pub solve_spans: Vec<SpanTiming>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Pass,
+    Fail,
+    Error,
+    #[serde(rename = "over_budget")]
+    OverBudget,
+    Unverified,
+}
+
+impl RunResult {
+    pub fn status(&self) -> Status {
+        if self.error.is_some() {
+            return Status::Error;
+        }
+
+        match (&self.answer, &self.expected) {
+            (Some(answer), Some(expected)) if answer.to_string() != *expected => Status::Fail,
+            (None, Some(_)) => Status::Fail,
+            _ if self.over_budget => Status::OverBudget,
+            (_, None) => Status::Unverified,
+            _ => Status::Pass,
+        }
+    }
+}
+
+impl Status {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pass => "pass",
+            Self::Fail => "fail",
+            Self::Error => "error",
+            Self::OverBudget => "over_budget",
+            Self::Unverified => "unverified",
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pass => write!(f, "PASS"),
+            Self::Fail => write!(f, "FAIL"),
+            Self::Error => write!(f, "ERROR"),
+            Self::OverBudget => write!(f, "OVER BUDGET"),
+            Self::Unverified => write!(f, "-"),
+        }
+    }
+}
+
+/*--------------------------------------------------------------------------------------
+  Run Functions
+--------------------------------------------------------------------------------------*/
+
+pub fn answer_path(input: &Path, part: Part) -> PathBuf {
+    let stem = input.file_stem().unwrap_or_default().to_string_lossy();
+
+    // Part-specific inputs (like `example-part2.txt`) already include the part in their name
+    let part_suffix = format!("-{part}");
+    let stem = stem.strip_suffix(&part_suffix).unwrap_or(&stem);
+
+    input.with_file_name(format!("{stem}-{part}-answer.txt"))
+}
+
+pub fn run(
+    solution: &dyn Solution,
+    part: Part,
+    input: &InputSource,
+    parameters: &Parameters,
+) -> RunResult {
+    run_parts(solution, &[part], input, parameters).remove(0)
+}
+
+// Reads and parses the input once, then solves each part from the parsed input
+pub fn run_parts(
+    solution: &dyn Solution,
+    parts: &[Part],
+    input: &InputSource,
+    parameters: &Parameters,
+) -> Vec<RunResult> {
+    let (parsed_input, parse_stats) = parse_input(solution, input);
+
+    parts
+        .iter()
+        .map(|&part| {
+            solve_part(
+                solution,
+                part,
+                input,
+                parsed_input.as_ref().map_err(String::as_str),
+                &parse_stats,
+                parameters,
+            )
+        })
+        .collect()
+}
+
+// What each part's result reports about the shared parse
+#[derive(Debug, Clone, Default)]
+struct ParseStats {
+    duration: Duration,
+    allocations: Option<AllocationStats>,
+    spans: Vec<SpanTiming>,
+}
+
+type Parsed<T> = (Result<T, String>, ParseStats);
+
+fn parse_input(solution: &dyn Solution, input: &InputSource) -> Parsed<ParsedInput> {
+    let mut parse_stats = ParseStats::default();
+    let parsed_input = input.read().and_then(|input| {
+        let start = Instant::now();
+        let ((parsed_input, spans), allocations) =
+            count_allocations(|| profile_spans(|| solution.parse(&input)));
+        parse_stats = ParseStats {
+            duration: start.elapsed(),
+            allocations,
+            spans,
+        };
+        parsed_input
+    });
+
+    (
+        parsed_input.map_err(|error| format!("{error:#}")),
+        parse_stats,
+    )
+}
+
+fn solve_part(
+    solution: &dyn Solution,
+    part: Part,
+    input: &InputSource,
+    parsed_input: Result<&ParsedInput, &str>,
+    parse_stats: &ParseStats,
+    parameters: &Parameters,
+) -> RunResult {
+    let expected = input
+        .path()
+        .and_then(|path| get_answer(&answer_path(path, part)));
+
+    let mut solve_duration = Duration::ZERO;
+    let mut solve_allocations = None;
+    let mut solve_spans = Vec::new();
+    let outcome = match parsed_input {
+        Ok(parsed_input) => {
+            let start = Instant::now();
+            let ((answer, spans), allocations) = count_allocations(|| {
+                profile_spans(|| solution.solve(part, parsed_input, parameters))
+            });
+            solve_duration = start.elapsed();
+            solve_allocations = allocations;
+            solve_spans = spans;
+            answer.map_err(|error| format!("{error:#}"))
+        }
+        Err(error) => Err(error.to_string()),
+    };
+
+    let (answer, error) = match outcome {
+        Ok(answer) => (Some(answer), None),
+        Err(error) => (None, Some(error)),
+    };
+
+    RunResult {
+        day: solution.day(),
+        part,
+        input: input.clone(),
+        answer,
+        expected,
+        error,
+        parse_duration: parse_stats.duration,
+        solve_duration,
+        over_budget: false,
+        parse_allocations: parse_stats.allocations,
+        solve_allocations,
+        parse_spans: parse_stats.spans.clone(),
+        solve_spans,
+    }
+}
+
+/*--------------------------------------------------------------------------------------
+  Run All
+--------------------------------------------------------------------------------------*/
+
+// The days' inputs are parsed and their parts solved on a pool of `jobs` workers. Each parse and
+// solve is timed on its own, so the totals match running the days one at a time, and the results
+// are returned in registry order regardless of which worker finished first.
+//
+// A parse or solve that runs past the day's timeout is reported as an error, and a day whose total
+// time exceeds its budget is over budget. A timed out thread can't be stopped and keeps running in
+// the background, which would slow down everything after it, so once anything times out the
+// remaining parses and solves are skipped (and reported as errors) rather than run.
+pub fn run_all(
+    solutions: &[&'static dyn Solution],
+    data_dir: &Path,
+    jobs: usize,
+    budgets: &Budgets,
+) -> Vec<RunResult> {
+    let days: Vec<DayRun> = solutions
+        .iter()
+        .filter_map(|solution| {
+            let input_path = data_dir.join(solution.name()).join("input.txt");
+
+            if !input_path.is_file() {
+                log::warn!("Skipping {}: {:?} not found", solution.name(), input_path);
+                return None;
+            }
+
+            Some(DayRun {
+                solution: *solution,
+                input: InputSource::File(input_path),
+                parameters: solution.default_parameters(),
+                budget: budgets.get(solution.day()),
+                parsed_input: OnceLock::new(),
+                results: solution.parts().iter().map(|_| OnceLock::new()).collect(),
+            })
+        })
+        .collect();
+
+    let queue = Mutex::new(TaskQueue {
+        tasks: (0..days.len()).map(Task::Parse).collect(),
+        active: 0,
+        timed_out: false,
+    });
+    let ready = Condvar::new();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, days.len().max(1)) {
+            scope.spawn(|| run_tasks(&days, &queue, &ready));
+        }
+    });
+
+    days.into_iter()
+        .flat_map(|day| {
+            let mut results: Vec<RunResult> = day
+                .results
+                .into_iter()
+                .map(|result| result.into_inner().unwrap())
+                .collect();
+
+            let total = day
+                .parsed_input
+                .get()
+                .map_or(Duration::ZERO, |(_, parse_stats)| parse_stats.duration)
+                + results.iter().map(|result| result.solve_duration).sum();
+            if day.budget.budget.is_some_and(|budget| total > budget) {
+                for result in &mut results {
+                    result.over_budget = true;
+                }
+            }
+
+            results
+        })
+        .collect()
+}
+
+struct DayRun {
+    solution: &'static dyn Solution,
+    input: InputSource,
+    parameters: Parameters,
+    budget: Budget,
+    parsed_input: OnceLock<Parsed<Arc<ParsedInput>>>,
+    results: Vec<OnceLock<RunResult>>,
+}
+
+enum Task {
+    Parse(usize),
+    Solve(usize, usize),
+}
+
+struct TaskQueue {
+    tasks: VecDeque<Task>,
+    active: usize,
+    timed_out: bool,
+}
+
+const SKIPPED: &str = "Skipped after an earlier parse or solve timed out";
+
+// Workers exit once the queue is empty and no running task can add more
+fn run_tasks(days: &[DayRun], queue: &Mutex<TaskQueue>, ready: &Condvar) {
+    loop {
+        let (task, skip) = {
+            let mut queue = queue.lock().unwrap();
+            loop {
+                if let Some(task) = queue.tasks.pop_front() {
+                    queue.active += 1;
+                    break (task, queue.timed_out);
+                }
+                if queue.active == 0 {
+                    return;
+                }
+                queue = ready.wait(queue).unwrap();
+            }
+        };
+
+        let mut timed_out = false;
+        let new_tasks = match task {
+            Task::Parse(day) => {
+                let run = &days[day];
+                run.parsed_input.get_or_init(|| {
+                    let (parsed_input, interrupted) = parse_day(run, skip);
+                    timed_out = interrupted;
+                    parsed_input
+                });
+                (0..run.results.len())
+                    .map(|part| Task::Solve(day, part))
+                    .collect()
+            }
+            Task::Solve(day, part_index) => {
+                let run = &days[day];
+                run.results[part_index].get_or_init(|| {
+                    let (result, interrupted) = solve_day_part(run, part_index, skip);
+                    timed_out = interrupted;
+                    result
+                });
+                Vec::new()
+            }
+        };
+
+        let mut queue = queue.lock().unwrap();
+        queue.timed_out |= timed_out;
+        // Solve a parsed day's parts before parsing more days
+        for task in new_tasks.into_iter().rev() {
+            queue.tasks.push_front(task);
+        }
+        queue.active -= 1;
+        ready.notify_all();
+    }
+}
+
+// Returns whether the parse timed out, along with the parsed input
+fn parse_day(run: &DayRun, skip: bool) -> (Parsed<Arc<ParsedInput>>, bool) {
+    if skip {
+        return ((Err(SKIPPED.to_string()), ParseStats::default()), false);
+    }
+
+    let solution = run.solution;
+    let input = run.input.clone();
+
+    match run_with_timeout(run.budget.timeout, move || parse_input(solution, &input)) {
+        Ok((parsed_input, parse_stats)) => ((parsed_input.map(Arc::new), parse_stats), false),
+        Err(interrupted) => {
+            let parse_stats = ParseStats {
+                duration: run.budget.timeout.unwrap_or_default(),
+                ..ParseStats::default()
+            };
+            let timed_out = matches!(interrupted, Interrupted::TimedOut(_));
+            ((Err(interrupted.to_string()), parse_stats), timed_out)
+        }
+    }
+}
+
+// Returns whether the solve timed out, along with the part's result
+fn solve_day_part(run: &DayRun, part_index: usize, skip: bool) -> (RunResult, bool) {
+    let solution = run.solution;
+    let part = solution.parts()[part_index];
+    let (parsed_input, parse_stats) = run.parsed_input.get().unwrap();
+
+    let parsed_input = match parsed_input {
+        Ok(parsed_input) if !skip => parsed_input.clone(),
+        _ => {
+            let error = parsed_input.as_ref().err().map_or(SKIPPED, String::as_str);
+            let result = solve_part(
+                solution,
+                part,
+                &run.input,
+                Err(error),
+                parse_stats,
+                &run.parameters,
+            );
+            return (result, false);
+        }
+    };
+
+    let input = run.input.clone();
+    let parameters = run.parameters.clone();
+    let stats = parse_stats.clone();
+    let result = run_with_timeout(run.budget.timeout, move || {
+        solve_part(
+            solution,
+            part,
+            &input,
+            Ok(&parsed_input),
+            &stats,
+            &parameters,
+        )
+    });
+
+    match result {
+        Ok(result) => (result, false),
+        Err(interrupted) => {
+            let mut result = solve_part(
+                solution,
+                part,
+                &run.input,
+                Err(&interrupted.to_string()),
+                parse_stats,
+                &run.parameters,
+            );
+            result.solve_duration = run.budget.timeout.unwrap_or_default();
+            (result, matches!(interrupted, Interrupted::TimedOut(_)))
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Interrupted {
+    TimedOut(Duration),
+    Panicked(String),
+}
+
+impl Display for Interrupted {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TimedOut(timeout) => write!(f, "Timed out after {timeout:.2?}"),
+            Self::Panicked(message) => write!(f, "Panicked: {message}"),
+        }
+    }
+}
+
+// Runs `f` on its own thread when there is a timeout. A timed out thread is abandoned, since
+// threads can't be stopped, so it keeps using a CPU until `f` returns (or the process exits). A
+// panicking solution is reported as an error either way, rather than stopping the other workers.
+pub(crate) fn run_with_timeout<T: Send + 'static>(
+    timeout: Option<Duration>,
+    f: impl FnOnce() -> T + Send + 'static,
+) -> Result<T, Interrupted> {
+    let Some(timeout) = timeout else {
+        return catch_panic(f);
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let _ = sender.send(catch_panic(f));
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(_) => Err(Interrupted::TimedOut(timeout)),
+    }
+}
+
+fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, Interrupted> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        Interrupted::Panicked(message)
+    })
+}
+
+/*--------------------------------------------------------------------------------------
+  Answer Recording
+--------------------------------------------------------------------------------------*/
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Recorded {
+    Written(PathBuf),
+    Unchanged(PathBuf),
+    Replaced { path: PathBuf, previous: String },
+}
+
+// Writes a successful result's answer next to its input file. A different recorded answer is only
+// replaced when forced.
+pub fn record_answer(result: &RunResult, force: bool) -> Result<Recorded> {
+    let answer = match (&result.answer, &result.error) {
+        (Some(answer), _) => answer.to_string(),
+        (None, error) => bail!(
+            "No answer to record: {}",
+            error
+                .as_deref()
+                .unwrap_or("the solution returned no answer")
+        ),
+    };
+
+    let input_path = result
+        .input
+        .path()
+        .ok_or_else(|| anyhow!("Answers can only be recorded for input files"))?;
+    let path = answer_path(&locate_data_file(input_path), result.part);
+
+    let previous = fs::read_to_string(&path).unwrap_or_default();
+    let previous = previous.trim();
+
+    let recorded = if previous.is_empty() {
+        Recorded::Written(path.clone())
+    } else if previous == answer {
+        return Ok(Recorded::Unchanged(path));
+    } else if force {
+        Recorded::Replaced {
+            path: path.clone(),
+            previous: previous.to_string(),
+        }
+    } else {
+        bail!("{path:?} records {previous}, not {answer}; use --force to replace it");
+    };
+
+    fs::write(&path, format!("{answer}\n")).with_context(|| format!("Failed to write {path:?}"))?;
+    Ok(recorded)
+}
+
+/*--------------------------------------------------------------------------------------
+  Results Table
+--------------------------------------------------------------------------------------*/
+
+// The allocation columns (for the solves) are only shown when allocations were counted
+pub fn results_table(results: &[RunResult]) -> String {
+    let show_allocations = results
+        .iter()
+        .any(|result| result.solve_allocations.is_some());
+
+    let mut header: Vec<String> = [
+        "Day", "Part", "Answer", "Expected", "Status", "Parse", "Solve",
+    ]
+    .map(String::from)
+    .to_vec();
+    if show_allocations {
+        header.extend(["Allocs", "Allocated", "Peak heap"].map(String::from));
+    }
+
+    let rows: Vec<Vec<String>> = results
+        .iter()
+        .map(|result| {
+            let mut row = vec![
+                result.day.to_string(),
+                result.part.to_string(),
+                result
+                    .answer
+                    .as_ref()
+                    .map_or_else(|| "-".to_string(), Answer::to_string),
+                result.expected.clone().unwrap_or_else(|| "-".to_string()),
+                result.status().to_string(),
+                format!("{:.2?}", result.parse_duration),
+                format!("{:.2?}", result.solve_duration),
+            ];
+            if show_allocations {
+                row.extend(match result.solve_allocations {
+                    Some(stats) => [
+                        stats.allocations.to_string(),
+                        format_bytes(stats.allocated_bytes),
+                        format_bytes(stats.peak_bytes),
+                    ],
+                    None => ["-", "-", "-"].map(String::from),
+                });
+            }
+            row
+        })
+        .collect();
+
+    let mut widths: Vec<usize> = header.iter().map(|column| column.chars().count()).collect();
+    for row in &rows {
+        for (width, column) in widths.iter_mut().zip(row) {
+            *width = (*width).max(column.chars().count());
+        }
+    }
+
+    // Parts solved from the same input share its parse time, so count it once
+    let parse_total: Duration = results
+        .iter()
+        .unique_by(|result| (result.day, result.input.to_string()))
+        .map(|result| result.parse_duration)
+        .sum();
+    let solve_total: Duration = results.iter().map(|result| result.solve_duration).sum();
+    let total = parse_total + solve_total;
+
+    let mut table: Vec<String> = std::iter::once(&header)
+        .chain(rows.iter())
+        .map(|row| {
+            row.iter()
+                .zip(&widths)
+                .map(|(column, width)| format!("{column:<width$}"))
+                .collect::<Vec<_>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        })
+        .collect();
+
+    table.extend(results.iter().filter_map(|result| {
+        let error = result.error.as_ref()?;
+        Some(format!("Day {} {}: {}", result.day, result.part, error))
+    }));
+
+    table.push(format!(
+        "Total time: {total:.2?} (parse {parse_total:.2?}, solve {solve_total:.2?})"
+    ));
+    table.join("\n")
+}
+
+/*-------------------------------------------------------------------------------------------------
+  Tests
+-------------------------------------------------------------------------------------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(answer: Option<i64>, expected: Option<&str>) -> RunResult {
+        RunResult {
+            day: 1,
+            part: Part::Part1,
+            input: InputSource::File(PathBuf::from("input.txt")),
+            answer: answer.map(Answer::Integer),
+            expected: expected.map(String::from),
+            error: None,
+            parse_duration: Duration::from_millis(1),
+            solve_duration: Duration::from_millis(5),
+            over_budget: false,
+            parse_allocations: None,
+            solve_allocations: None,
+            parse_spans: Vec::new(),
+            solve_spans: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_answer_path() {
+        assert_eq!(
+            answer_path(Path::new("../data/day3/example-part2.txt"), Part::Part2),
+            PathBuf::from("../data/day3/example-part2-answer.txt")
+        );
+        assert_eq!(
+            answer_path(Path::new("../data/day1/input.txt"), Part::Part1),
+            PathBuf::from("../data/day1/input-part1-answer.txt")
+        );
+    }
+
+    #[test]
+    fn test_status() {
+        assert_eq!(result(Some(42), Some("42")).status(), Status::Pass);
+        assert_eq!(result(Some(41), Some("42")).status(), Status::Fail);
+        assert_eq!(result(None, Some("42")).status(), Status::Fail);
+        assert_eq!(result(Some(42), None).status(), Status::Unverified);
+
+        let mut over_budget = result(Some(42), Some("42"));
+        over_budget.over_budget = true;
+        assert_eq!(over_budget.status(), Status::OverBudget);
+        over_budget.answer = Some(Answer::Integer(41));
+        assert_eq!(over_budget.status(), Status::Fail);
+
+        let mut errored = result(None, Some("42"));
+        errored.error = Some("Day 1, line 3, column 1: Missing value".to_string());
+        assert_eq!(errored.status(), Status::Error);
+    }
+
+    #[test]
+    fn test_run_all() {
+        let data_dir = std::env::temp_dir().join(format!("aoc24-run-all-{}", std::process::id()));
+        for (day, input) in [
+            ("day1", "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n"),
+            (
+                "day3",
+                "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))",
+            ),
+        ] {
+            fs::create_dir_all(data_dir.join(day)).unwrap();
+            fs::write(data_dir.join(day).join("input.txt"), input).unwrap();
+        }
+
+        // Results are in registry order, whichever worker finishes first
+        let answers = |jobs| {
+            run_all(crate::SOLUTIONS, &data_dir, jobs, &Budgets::default())
+                .iter()
+                .map(|result| {
+                    let answer = result.answer.as_ref().unwrap().to_string();
+                    (result.day, result.part, answer)
+                })
+                .collect::<Vec<_>>()
+        };
+        let expected = [
+            (1, Part::Part1, "11".to_string()),
+            (1, Part::Part2, "31".to_string()),
+            (3, Part::Part1, "161".to_string()),
+            (3, Part::Part2, "48".to_string()),
+        ];
+        assert_eq!(answers(1), expected);
+        assert_eq!(answers(4), expected);
+
+        fs::remove_dir_all(data_dir).unwrap();
+    }
+
+    struct SlowDay;
+
+    impl Solution for SlowDay {
+        fn day(&self) -> Day {
+            25
+        }
+
+        fn title(&self) -> &'static str {
+            "Slow"
+        }
+
+        fn parse(&self, _: &str) -> Result<ParsedInput> {
+            thread::sleep(Duration::from_millis(200));
+            Ok(ParsedInput::new(()))
+        }
+
+        fn part1(&self, _: &ParsedInput, _: &Parameters) -> Result<Answer> {
+            Ok(Answer::Integer(1))
+        }
+
+        fn part2(&self, _: &ParsedInput, _: &Parameters) -> Result<Answer> {
+            Ok(Answer::Integer(2))
+        }
+    }
+
+    #[test]
+    fn test_run_all_timeout() {
+        let data_dir =
+            std::env::temp_dir().join(format!("aoc24-run-all-timeout-{}", std::process::id()));
+        for day in ["day1", "day25"] {
+            fs::create_dir_all(data_dir.join(day)).unwrap();
+            fs::write(data_dir.join(day).join("input.txt"), "3   4\n").unwrap();
+        }
+        let budgets = Budgets::parse("[day25]\ntimeout = \"10ms\"").unwrap();
+
+        // Nothing runs after the slow day times out
+        let solutions: [&'static dyn Solution; 2] = [&SlowDay, crate::SOLUTIONS[0]];
+        let errors: Vec<_> = run_all(&solutions, &data_dir, 1, &budgets)
+            .into_iter()
+            .map(|result| (result.day, result.error.unwrap_or_default()))
+            .collect();
+        assert_eq!(
+            errors,
+            [
+                (25, "Timed out after 10.00ms".to_string()),
+                (25, "Timed out after 10.00ms".to_string()),
+                (1, SKIPPED.to_string()),
+                (1, SKIPPED.to_string()),
+            ]
+        );
+
+        fs::remove_dir_all(data_dir).unwrap();
+    }
+
+    #[test]
+    fn test_record_answer() {
+        let day_dir = std::env::temp_dir().join(format!("aoc24-record-{}", std::process::id()));
+        fs::create_dir_all(&day_dir).unwrap();
+        let answer_file = day_dir.join("example-part1-answer.txt");
+
+        let mut recorded = result(Some(42), None);
+        recorded.input = InputSource::File(day_dir.join("example.txt"));
+        assert_eq!(
+            record_answer(&recorded, false).unwrap(),
+            Recorded::Written(answer_file.clone())
+        );
+        assert_eq!(fs::read_to_string(&answer_file).unwrap(), "42\n");
+        assert_eq!(
+            record_answer(&recorded, false).unwrap(),
+            Recorded::Unchanged(answer_file.clone())
+        );
+
+        // Differing answers are only replaced when forced
+        recorded.answer = Some(Answer::Integer(41));
+        assert!(record_answer(&recorded, false).is_err());
+        assert_eq!(
+            record_answer(&recorded, true).unwrap(),
+            Recorded::Replaced {
+                path: answer_file.clone(),
+                previous: "42".to_string()
+            }
+        );
+        assert_eq!(fs::read_to_string(&answer_file).unwrap(), "41\n");
+
+        recorded.input = InputSource::Stdin;
+        assert!(record_answer(&recorded, true).is_err());
+
+        fs::remove_dir_all(day_dir).unwrap();
+    }
+
+    #[test]
+    fn test_results_table() {
+        let mut part2 = result(Some(7), None);
+        part2.part = Part::Part2;
+
+        let table = results_table(&[result(Some(42), Some("42")), part2]);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(
+            lines[0],
+            "Day  Part   Answer  Expected  Status  Parse   Solve"
+        );
+        assert_eq!(
+            lines[1],
+            "1    part1  42      42        PASS    1.00ms  5.00ms"
+        );
+        assert_eq!(
+            lines[2],
+            "1    part2  7       -         -       1.00ms  5.00ms"
+        );
+        assert_eq!(
+            lines[3],
+            "Total time: 11.00ms (parse 1.00ms, solve 10.00ms)"
+        );
+
+        let mut counted = result(Some(42), Some("42"));
+        counted.solve_allocations = Some(AllocationStats {
+            allocations: 3,
+            allocated_bytes: 2048,
+            peak_bytes: 1024,
+        });
+        let table = results_table(&[counted]);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(
+            lines[0],
+            "Day  Part   Answer  Expected  Status  Parse   Solve   Allocs  Allocated  Peak heap"
+        );
+        assert_eq!(
+            lines[1],
+            "1    part1  42      42        PASS    1.00ms  5.00ms  3       2.00KiB    1.00KiB"
+        );
+    }
+}
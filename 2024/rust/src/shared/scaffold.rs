@@ -0,0 +1,194 @@
+use crate::shared::solution::Day;
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/*-------------------------------------------------------------------------------------------------
+  Day Scaffolding
+-------------------------------------------------------------------------------------------------*/
+
+const DAY_TEMPLATE: &str = include_str!("templates/day.rs.in");
+
+// Placeholder data files; empty answer files are treated as not yet known
+const DATA_FILES: [&str; 3] = [
+    "example.txt",
+    "example-part1-answer.txt",
+    "example-part2-answer.txt",
+];
+
+// Generates `src/day{N}.rs`, registers it in `src/lib.rs`, and creates the day's data directory.
+// Nothing is written if the module already exists, and existing data files are left untouched.
+pub fn new_day(package_dir: &Path, data_dir: &Path, day: Day, title: &str) -> Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        bail!("Invalid day {day}; expected 1 through 25");
+    }
+
+    let title = title.trim();
+    if title.is_empty() {
+        bail!("The puzzle title must not be empty");
+    }
+
+    let module_path = package_dir.join("src").join(format!("day{day}.rs"));
+    if module_path.exists() {
+        bail!("{module_path:?} already exists");
+    }
+
+    let lib_path = package_dir.join("src").join("lib.rs");
+    let lib =
+        fs::read_to_string(&lib_path).with_context(|| format!("Failed to read {lib_path:?}"))?;
+    let lib = register_day(&lib, day)?;
+
+    let mut created = Vec::new();
+
+    fs::write(&module_path, day_module(day, title))
+        .with_context(|| format!("Failed to write {module_path:?}"))?;
+    created.push(module_path);
+
+    fs::write(&lib_path, lib).with_context(|| format!("Failed to write {lib_path:?}"))?;
+    created.push(lib_path);
+
+    let day_dir = data_dir.join(format!("day{day}"));
+    fs::create_dir_all(&day_dir).with_context(|| format!("Failed to create {day_dir:?}"))?;
+    for file_name in DATA_FILES {
+        let file_path = day_dir.join(file_name);
+        if !file_path.exists() {
+            fs::write(&file_path, "").with_context(|| format!("Failed to write {file_path:?}"))?;
+            created.push(file_path);
+        }
+    }
+
+    Ok(created)
+}
+
+fn day_module(day: Day, title: &str) -> String {
+    DAY_TEMPLATE
+        .replace("{{DAY}}", &day.to_string())
+        .replace("{{TITLE_LITERAL}}", &format!("{title:?}"))
+        .replace("{{TITLE}}", title)
+}
+
+/*--------------------------------------------------------------------------------------
+  Registration
+--------------------------------------------------------------------------------------*/
+
+// Adds the `pub mod` declaration (in rustfmt's sorted order) and the registry entry (in day order)
+fn register_day(lib: &str, day: Day) -> Result<String> {
+    let mut lines: Vec<String> = lib.lines().map(String::from).collect();
+
+    let module = format!("pub mod day{day};");
+    if lines.contains(&module) {
+        bail!("Day {day} is already registered in lib.rs");
+    }
+
+    let module_lines: Vec<usize> = (0..lines.len())
+        .filter(|&index| module_day(&lines[index]).is_some())
+        .collect();
+    let (Some(&first), Some(&last)) = (module_lines.first(), module_lines.last()) else {
+        bail!("Could not find the solution modules in lib.rs");
+    };
+
+    let mut modules: Vec<String> = lines[first..=last].to_vec();
+    modules.push(module);
+    modules.sort_by(|a, b| a.trim_end_matches(';').cmp(b.trim_end_matches(';')));
+    lines.splice(first..=last, modules);
+
+    let registry_lines: Vec<(usize, Day)> = (0..lines.len())
+        .filter_map(|index| Some((index, registry_day(&lines[index])?)))
+        .collect();
+    let (Some(&(first, _)), Some(&(last, _))) = (registry_lines.first(), registry_lines.last())
+    else {
+        bail!("Could not find the solution registry in lib.rs");
+    };
+
+    let insert_at = registry_lines
+        .iter()
+        .find(|(_, registered)| *registered > day)
+        .map_or(last + 1, |(index, _)| *index);
+    let indent = &lines[first][..lines[first].len() - lines[first].trim_start().len()];
+    lines.insert(insert_at, format!("{indent}&day{day}::Day{day},"));
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn module_day(line: &str) -> Option<Day> {
+    line.strip_prefix("pub mod day")?
+        .strip_suffix(';')?
+        .parse()
+        .ok()
+}
+
+fn registry_day(line: &str) -> Option<Day> {
+    let (module, name) = line
+        .trim()
+        .strip_prefix('&')?
+        .strip_suffix(',')?
+        .split_once("::")?;
+    let day = module.strip_prefix("day")?.parse().ok()?;
+    (name == format!("Day{day}")).then_some(day)
+}
+
+/*-------------------------------------------------------------------------------------------------
+  Tests
+-------------------------------------------------------------------------------------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB: &str = "\
+pub mod day1;
+pub mod day10;
+pub mod day9;
+
+pub static SOLUTIONS: &[&dyn Solution] = &[
+    &day1::Day1,
+    &day9::Day9,
+    &day10::Day10,
+];
+";
+
+    #[test]
+    fn test_register_day() {
+        let lib = register_day(LIB, 2).unwrap();
+        assert_eq!(
+            lib,
+            "\
+pub mod day1;
+pub mod day10;
+pub mod day2;
+pub mod day9;
+
+pub static SOLUTIONS: &[&dyn Solution] = &[
+    &day1::Day1,
+    &day2::Day2,
+    &day9::Day9,
+    &day10::Day10,
+];
+"
+        );
+
+        assert!(register_day(LIB, 9).is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc24-scaffold-{}", std::process::id()));
+        let package_dir = root.join("rust");
+        let data_dir = root.join("data");
+        fs::create_dir_all(package_dir.join("src")).unwrap();
+        fs::write(package_dir.join("src/lib.rs"), LIB).unwrap();
+
+        let created = new_day(&package_dir, &data_dir, 2, "Red-Nosed Reports").unwrap();
+        assert_eq!(created.len(), 5);
+
+        let module = fs::read_to_string(package_dir.join("src/day2.rs")).unwrap();
+        assert!(module.contains("  Day 2: Red-Nosed Reports\n"));
+        assert!(module.contains("pub struct Day2;"));
+        assert!(data_dir.join("day2/example-part2-answer.txt").is_file());
+
+        // Existing work is never overwritten
+        assert!(new_day(&package_dir, &data_dir, 2, "Red-Nosed Reports").is_err());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
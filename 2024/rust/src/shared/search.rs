@@ -0,0 +1,371 @@
+use num::Zero;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/*-------------------------------------------------------------------------------------------------
+  Graph Search
+-------------------------------------------------------------------------------------------------*/
+
+// The searches work over any state type (a position, a position and direction, ...). Each one
+// starts from a single state, expands states with the caller's successor function, and stops once
+// the shortest distance to a goal state is settled.
+//
+// With `Predecessors::All`, every predecessor on a shortest path is kept, so all of the shortest
+// paths can be recovered; the search then also keeps going until every goal state at the
+// shortest distance has been reached.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Predecessors {
+    #[default]
+    First,
+    All,
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchResult<S, C> {
+    pub distances: HashMap<S, C>,
+    pub predecessors: HashMap<S, Vec<S>>,
+    // The goal states reached at the shortest distance, in the order they were reached
+    pub goals: Vec<S>,
+}
+
+impl<S, C> SearchResult<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy,
+{
+    fn new(start: S) -> Self {
+        Self {
+            distances: HashMap::new(),
+            predecessors: HashMap::from([(start, Vec::new())]),
+            goals: Vec::new(),
+        }
+    }
+
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    pub fn goal(&self) -> Option<&S> {
+        self.goals.first()
+    }
+
+    pub fn goal_distance(&self) -> Option<C> {
+        self.distance(self.goal()?)
+    }
+
+    // A shortest path from the start to the first goal reached, including both ends
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.path_to(self.goal()?)
+    }
+
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        let mut path = vec![state.clone()];
+        let mut predecessors = self.predecessors.get(state)?;
+
+        while let Some(predecessor) = predecessors.first() {
+            path.push(predecessor.clone());
+            predecessors = &self.predecessors[predecessor];
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    // Every state on a shortest path to any of the goals; search with `Predecessors::All` to
+    // include all of the shortest paths
+    pub fn shortest_path_states(&self) -> HashSet<S> {
+        let mut states: HashSet<S> = HashSet::new();
+        let mut stack: Vec<&S> = self.goals.iter().collect();
+
+        while let Some(state) = stack.pop() {
+            if states.insert(state.clone()) {
+                stack.extend(self.predecessors.get(state).into_iter().flatten());
+            }
+        }
+
+        states
+    }
+
+    fn add_predecessor(&mut self, state: S, predecessor: &S, distance: Ordering) {
+        let predecessors = self.predecessors.entry(state).or_default();
+        match distance {
+            Ordering::Less => *predecessors = vec![predecessor.clone()],
+            Ordering::Equal if !predecessors.contains(predecessor) => {
+                predecessors.push(predecessor.clone())
+            }
+            _ => {}
+        }
+    }
+}
+
+/*--------------------------------------------------------------------------------------
+  Breadth-First Search
+--------------------------------------------------------------------------------------*/
+
+// Every step costs one, so distances are the number of steps from the start
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+    predecessors: Predecessors,
+) -> SearchResult<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut result = SearchResult::new(start.clone());
+    result.distances.insert(start.clone(), 0);
+
+    let mut queue = VecDeque::from([start]);
+    while let Some(state) = queue.pop_front() {
+        let distance = result.distances[&state];
+        if result.goal_distance().is_some_and(|goal| distance > goal) {
+            break;
+        }
+
+        if is_goal(&state) {
+            result.goals.push(state);
+            if predecessors == Predecessors::First {
+                break;
+            }
+            continue;
+        }
+
+        for next in successors(&state) {
+            let next_distance = distance + 1;
+            match result.distances.get(&next) {
+                None => {
+                    result.distances.insert(next.clone(), next_distance);
+                    result.add_predecessor(next.clone(), &state, Ordering::Less);
+                    queue.push_back(next);
+                }
+                Some(&known) if known == next_distance && predecessors == Predecessors::All => {
+                    result.add_predecessor(next, &state, Ordering::Equal);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    result
+}
+
+/*--------------------------------------------------------------------------------------
+  Dijkstra and A*
+--------------------------------------------------------------------------------------*/
+
+// The successor function returns each next state with the cost of the step to it. Step costs must
+// be positive: with zero-cost cycles, `Predecessors::All` would record predecessor cycles that
+// `path_to` can't walk back out of.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+    predecessors: Predecessors,
+) -> SearchResult<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::zero(), is_goal, predecessors)
+}
+
+// Step costs must be positive, as for `dijkstra`. The heuristic estimates the remaining cost to a
+// goal. It must never overestimate, and to find all of the shortest paths it must also be
+// consistent (never drop by more than a step's cost).
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+    predecessors: Predecessors,
+) -> SearchResult<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut result = SearchResult::new(start.clone());
+    result.distances.insert(start.clone(), C::zero());
+
+    let mut heap = BinaryHeap::from([QueuedState {
+        estimate: heuristic(&start),
+        distance: C::zero(),
+        state: start,
+    }]);
+
+    while let Some(QueuedState {
+        estimate,
+        distance,
+        state,
+    }) = heap.pop()
+    {
+        // Skip states that were reached by a shorter path after being queued
+        if distance > result.distances[&state] {
+            continue;
+        }
+
+        if result.goal_distance().is_some_and(|goal| estimate > goal) {
+            break;
+        }
+
+        if is_goal(&state) {
+            result.goals.push(state);
+            if predecessors == Predecessors::First {
+                break;
+            }
+            continue;
+        }
+
+        for (next, cost) in successors(&state) {
+            debug_assert!(cost > C::zero(), "step costs must be positive");
+            let next_distance = distance + cost;
+            let comparison = result
+                .distances
+                .get(&next)
+                .map_or(Ordering::Less, |known| next_distance.cmp(known));
+
+            match comparison {
+                Ordering::Less => {
+                    result.distances.insert(next.clone(), next_distance);
+                    result.add_predecessor(next.clone(), &state, Ordering::Less);
+                    heap.push(QueuedState {
+                        estimate: next_distance + heuristic(&next),
+                        distance: next_distance,
+                        state: next,
+                    });
+                }
+                Ordering::Equal if predecessors == Predecessors::All => {
+                    result.add_predecessor(next, &state, Ordering::Equal);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    result
+}
+
+struct QueuedState<S, C> {
+    estimate: C,
+    distance: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for QueuedState<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reverse ordering to make the heap a min-heap
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+impl<S, C: Ord> PartialOrd for QueuedState<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for QueuedState<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<S, C: Ord> Eq for QueuedState<S, C> {}
+
+/*-------------------------------------------------------------------------------------------------
+  Tests
+-------------------------------------------------------------------------------------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::grid_index::GridIndex;
+    use crate::shared::grid_map::GridMap;
+
+    const MAZE: &str = "\
+S..#
+.#..
+...E";
+
+    fn open_neighbors(map: &GridMap<i32, char>, position: GridIndex<i32>) -> Vec<GridIndex<i32>> {
+        map.neighbors(position)
+            .filter(|(_, &tile)| tile != '#')
+            .map(|(neighbor, _)| neighbor)
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let map: GridMap<i32, char> = MAZE.into();
+        let start = GridIndex::new(0, 0);
+        let goal = GridIndex::new(2, 3);
+
+        let result = bfs(
+            start,
+            |&position| open_neighbors(&map, position),
+            |&position| position == goal,
+            Predecessors::First,
+        );
+        assert_eq!(result.goal_distance(), Some(5));
+        let path = result.path().unwrap();
+        assert_eq!(path.len(), 6);
+        assert_eq!((path[0], path[5]), (start, goal));
+
+        // Both routes around the wall are shortest paths
+        let result = bfs(
+            start,
+            |&position| open_neighbors(&map, position),
+            |&position| position == goal,
+            Predecessors::All,
+        );
+        assert_eq!(result.shortest_path_states().len(), 10);
+
+        let unreachable = bfs(
+            start,
+            |&position| open_neighbors(&map, position),
+            |&position| position == GridIndex::new(0, 3),
+            Predecessors::First,
+        );
+        assert!(unreachable.path().is_none());
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar() {
+        let map: GridMap<i32, char> = MAZE.into();
+        let start = GridIndex::new(0, 0);
+        let goal = GridIndex::new(2, 3);
+
+        // Moves cost more further down, so going along the top and then down is shortest
+        let successors = |&position: &GridIndex<i32>| {
+            open_neighbors(&map, position)
+                .into_iter()
+                .map(|next| (next, 1 + next.row))
+                .collect::<Vec<_>>()
+        };
+
+        let result = dijkstra(start, successors, |&p| p == goal, Predecessors::All);
+        assert_eq!(result.goal_distance(), Some(9));
+        assert_eq!(
+            result.path().unwrap(),
+            [(0, 0), (0, 1), (0, 2), (1, 2), (1, 3), (2, 3)].map(GridIndex::from)
+        );
+        assert_eq!(result.shortest_path_states().len(), 6);
+
+        let manhattan = |position: &GridIndex<i32>| {
+            let remaining = (goal - *position).abs();
+            remaining.row + remaining.column
+        };
+        let result = astar(
+            start,
+            successors,
+            manhattan,
+            |&p| p == goal,
+            Predecessors::First,
+        );
+        assert_eq!(result.goal_distance(), Some(9));
+    }
+}
@@ -0,0 +1,308 @@
+use crate::shared::answers::Answer;
+use anyhow::{anyhow, Result};
+use std::any::{type_name, Any};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use strum::EnumIter;
+
+/*-------------------------------------------------------------------------------------------------
+  Solutions
+-------------------------------------------------------------------------------------------------*/
+
+pub type Day = u8;
+
+/*--------------------------------------------------------------------------------------
+  Solution Trait
+--------------------------------------------------------------------------------------*/
+
+pub trait Solution: Sync {
+    fn day(&self) -> Day;
+
+    fn title(&self) -> &'static str;
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[]
+    }
+
+    fn parts(&self) -> &'static [Part] {
+        &[Part::Part1, Part::Part2]
+    }
+
+    fn parse(&self, input: &str) -> Result<ParsedInput>;
+
+    fn part1(&self, input: &ParsedInput, parameters: &Parameters) -> Result<Answer>;
+
+    fn part2(&self, input: &ParsedInput, parameters: &Parameters) -> Result<Answer>;
+
+    /*-------------------------------------------------------------------------
+      Provided Methods
+    -------------------------------------------------------------------------*/
+
+    fn name(&self) -> String {
+        format!("day{}", self.day())
+    }
+
+    fn long_title(&self) -> String {
+        format!("Day {}: {}", self.day(), self.title())
+    }
+
+    fn default_parameters(&self) -> Parameters {
+        self.parameters()
+            .iter()
+            .map(|parameter| (parameter.name, parameter.default))
+            .collect()
+    }
+
+    fn solve(&self, part: Part, input: &ParsedInput, parameters: &Parameters) -> Result<Answer> {
+        match part {
+            Part::Part1 => self.part1(input, parameters),
+            Part::Part2 => self.part2(input, parameters),
+        }
+    }
+}
+
+/*--------------------------------------------------------------------------------------
+  Solution Error
+--------------------------------------------------------------------------------------*/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolutionError {
+    Unimplemented,
+    NoSolution,
+}
+
+impl Display for SolutionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unimplemented => write!(f, "Not implemented"),
+            Self::NoSolution => write!(f, "No solution found"),
+        }
+    }
+}
+
+impl std::error::Error for SolutionError {}
+
+/*--------------------------------------------------------------------------------------
+  Parsed Input
+--------------------------------------------------------------------------------------*/
+
+// A day's typed `Input`, erased so that the registry can parse once and then solve each part
+pub struct ParsedInput(Box<dyn Any + Send + Sync>);
+
+impl ParsedInput {
+    pub fn new<T: Any + Send + Sync>(input: T) -> Self {
+        Self(Box::new(input))
+    }
+
+    pub fn get<T: Any>(&self) -> Result<&T> {
+        self.0
+            .downcast_ref()
+            .ok_or_else(|| anyhow!("Parsed input is not a {}", type_name::<T>()))
+    }
+}
+
+/*--------------------------------------------------------------------------------------
+  Part
+--------------------------------------------------------------------------------------*/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, EnumIter)]
+pub enum Part {
+    Part1,
+    Part2,
+}
+
+impl Part {
+    pub fn number(&self) -> u8 {
+        match self {
+            Self::Part1 => 1,
+            Self::Part2 => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "part{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "part1" | "1" => Ok(Self::Part1),
+            "part2" | "2" => Ok(Self::Part2),
+            _ => Err(anyhow!("Invalid part: {:?}", s)),
+        }
+    }
+}
+
+/*--------------------------------------------------------------------------------------
+  Parameters
+--------------------------------------------------------------------------------------*/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParameterKind {
+    Value,
+    Flag,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Parameter {
+    pub name: &'static str,
+    pub help: &'static str,
+    pub default: &'static str,
+    pub kind: ParameterKind,
+    pub short: Option<char>,
+    // The only part that uses the parameter, or `None` if both parts use it
+    pub part: Option<Part>,
+}
+
+impl Parameter {
+    pub const fn value(name: &'static str, help: &'static str, default: &'static str) -> Self {
+        Self {
+            name,
+            help,
+            default,
+            kind: ParameterKind::Value,
+            short: None,
+            part: None,
+        }
+    }
+
+    pub const fn flag(name: &'static str, help: &'static str) -> Self {
+        Self {
+            name,
+            help,
+            default: "false",
+            kind: ParameterKind::Flag,
+            short: None,
+            part: None,
+        }
+    }
+
+    pub const fn short(mut self, short: char) -> Self {
+        self.short = Some(short);
+        self
+    }
+
+    pub const fn only(mut self, part: Part) -> Self {
+        self.part = Some(part);
+        self
+    }
+
+    pub fn applies_to(&self, part: Part) -> bool {
+        self.part.is_none() || self.part == Some(part)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Parameters {
+    values: HashMap<String, String>,
+}
+
+impl Parameters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(&mut self, name: &str, value: &str) {
+        self.values.insert(name.to_string(), value.to_string());
+    }
+
+    pub fn with(mut self, name: &str, value: &str) -> Self {
+        self.set(name, value);
+        self
+    }
+
+    pub fn get<T>(&self, name: &str) -> Result<T>
+    where
+        T: FromStr,
+        <T as FromStr>::Err: Display,
+    {
+        let value = self
+            .values
+            .get(name)
+            .ok_or_else(|| anyhow!("Missing parameter: {name}"))?;
+
+        value
+            .parse()
+            .map_err(|error| anyhow!("Invalid {name} parameter {value:?}: {error}"))
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for Parameters
+where
+    K: ToString,
+    V: ToString,
+{
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        Self {
+            values: iter
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+        }
+    }
+}
+
+/*-------------------------------------------------------------------------------------------------
+  Tests
+-------------------------------------------------------------------------------------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_display_and_parse() {
+        assert_eq!(Part::Part1.to_string(), "part1");
+        assert_eq!(Part::Part2.to_string(), "part2");
+        assert_eq!("part1".parse::<Part>().unwrap(), Part::Part1);
+        assert_eq!("2".parse::<Part>().unwrap(), Part::Part2);
+        assert!("part3".parse::<Part>().is_err());
+    }
+
+    #[test]
+    fn test_parsed_input() {
+        let input = ParsedInput::new(vec![1u8, 2, 3]);
+        assert_eq!(input.get::<Vec<u8>>().unwrap(), &[1, 2, 3]);
+        assert!(input.get::<String>().is_err());
+    }
+
+    #[test]
+    fn test_parameters() {
+        let parameters = Parameters::new()
+            .with("width", "11")
+            .with("visualize", "true");
+        assert_eq!(parameters.get::<isize>("width").unwrap(), 11);
+        assert!(parameters.get::<bool>("visualize").unwrap());
+    }
+
+    #[test]
+    fn test_parameter_parts() {
+        let width = Parameter::value("width", "Width", "101").only(Part::Part1);
+        assert!(width.applies_to(Part::Part1));
+        assert!(!width.applies_to(Part::Part2));
+
+        let size = Parameter::value("size", "Size", "70");
+        assert!(size.applies_to(Part::Part1) && size.applies_to(Part::Part2));
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        let error = Parameters::new().get::<isize>("height").unwrap_err();
+        assert_eq!(error.to_string(), "Missing parameter: height");
+
+        let error = Parameters::new()
+            .with("width", "wide")
+            .get::<isize>("width")
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid width parameter \"wide\": invalid digit found in string"
+        );
+    }
+}
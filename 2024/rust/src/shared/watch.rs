@@ -0,0 +1,307 @@
+use crate::shared::answer_files::{answer_cases, is_slow};
+use crate::shared::answers::Answer;
+use crate::shared::data_dir::data_parameters;
+use crate::shared::inputs::InputSource;
+use crate::shared::runner::{run_parts, RunResult, Status};
+use crate::shared::solution::{Part, Solution};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant, SystemTime};
+
+/*-------------------------------------------------------------------------------------------------
+  Day Checks
+-------------------------------------------------------------------------------------------------*/
+
+// Pairs each answer file with its input (like `build.rs` does for the data tests), and adds
+// `input.txt` for every part. Slow parts are skipped unless `include_slow` is set.
+pub fn day_cases(day_dir: &Path, parts: &[Part], include_slow: bool) -> Vec<(PathBuf, Vec<Part>)> {
+    let mut cases: BTreeMap<PathBuf, Vec<Part>> = BTreeMap::new();
+
+    for case in answer_cases(day_dir) {
+        if let Some(input_file) = case.input_file {
+            if include_slow || !case.slow {
+                cases
+                    .entry(day_dir.join(input_file))
+                    .or_default()
+                    .push(part(case.part));
+            }
+        }
+    }
+
+    let input = day_dir.join("input.txt");
+    if input.is_file() {
+        let input_parts = cases.entry(input).or_default();
+        for &part in parts {
+            let slow = is_slow(day_dir, "input", part.number());
+            if !input_parts.contains(&part) && (include_slow || !slow) {
+                input_parts.push(part);
+            }
+        }
+        input_parts.sort();
+    }
+
+    cases
+        .into_iter()
+        .filter(|(_, parts)| !parts.is_empty())
+        .collect()
+}
+
+fn part(number: u8) -> Part {
+    match number {
+        1 => Part::Part1,
+        _ => Part::Part2,
+    }
+}
+
+// Runs the solution against each of the day's examples and its input
+pub fn check_day(solution: &dyn Solution, data_dir: &Path, include_slow: bool) -> Vec<RunResult> {
+    let day_dir = data_dir.join(solution.name());
+
+    day_cases(&day_dir, solution.parts(), include_slow)
+        .into_iter()
+        .flat_map(|(input_path, parts)| {
+            let input = InputSource::File(input_path.clone());
+            match data_parameters(solution, &input_path) {
+                Ok(parameters) => run_parts(solution, &parts, &input, &parameters),
+                Err(error) => parts
+                    .into_iter()
+                    .map(|part| RunResult {
+                        day: solution.day(),
+                        part,
+                        input: input.clone(),
+                        answer: None,
+                        expected: None,
+                        error: Some(format!("{error:#}")),
+                        parse_duration: Duration::ZERO,
+                        solve_duration: Duration::ZERO,
+                        over_budget: false,
+                        parse_allocations: None,
+                        solve_allocations: None,
+                        parse_spans: Vec::new(),
+                        solve_spans: Vec::new(),
+                    })
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+// One line per input and part, like `example  part1  PASS  11`, and a line of totals
+pub fn check_summary(results: &[RunResult]) -> String {
+    let mut summary = String::new();
+
+    for result in results {
+        let input = match result.input.path().and_then(Path::file_stem) {
+            Some(stem) => stem.to_string_lossy().to_string(),
+            None => result.input.to_string(),
+        };
+        let status = result.status();
+        let detail = match (status, &result.answer, &result.error) {
+            (_, _, Some(error)) => error.clone(),
+            (Status::Fail, Some(answer), _) => format!(
+                "{answer} (expected {})",
+                result.expected.as_deref().unwrap_or("-")
+            ),
+            (_, answer, _) => answer
+                .as_ref()
+                .map_or_else(|| "-".to_string(), Answer::to_string),
+        };
+
+        writeln!(
+            summary,
+            "{input:<16} {}  {status:<5}  {detail}",
+            result.part
+        )
+        .unwrap();
+    }
+
+    let count = |status: Status| {
+        results
+            .iter()
+            .filter(|result| result.status() == status)
+            .count()
+    };
+    write!(
+        summary,
+        "{} passed, {} failed, {} errors, {} unverified",
+        count(Status::Pass),
+        count(Status::Fail),
+        count(Status::Error),
+        count(Status::Unverified)
+    )
+    .unwrap();
+
+    summary
+}
+
+/*-------------------------------------------------------------------------------------------------
+  File Watcher
+-------------------------------------------------------------------------------------------------*/
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+// Editors often write a file in several steps, so changes are collected until the files settle
+const SETTLE_INTERVAL: Duration = Duration::from_millis(200);
+
+type Snapshot = BTreeMap<PathBuf, (Option<SystemTime>, u64)>;
+
+// Polls the watched files and directories (recursively) for added, changed, and removed files
+pub struct Watcher {
+    paths: Vec<PathBuf>,
+    snapshot: Snapshot,
+}
+
+impl Watcher {
+    pub fn new(paths: Vec<PathBuf>) -> Self {
+        let snapshot = snapshot(&paths);
+        Self { paths, snapshot }
+    }
+
+    pub fn changes(&mut self) -> Vec<PathBuf> {
+        let snapshot = snapshot(&self.paths);
+
+        let mut changed: Vec<PathBuf> = snapshot
+            .iter()
+            .filter(|(path, state)| self.snapshot.get(*path) != Some(state))
+            .map(|(path, _)| path.clone())
+            .chain(
+                self.snapshot
+                    .keys()
+                    .filter(|path| !snapshot.contains_key(*path))
+                    .cloned(),
+            )
+            .collect();
+        changed.sort();
+
+        self.snapshot = snapshot;
+        changed
+    }
+
+    // Waits for changes, or returns `None` once `stop` is set
+    pub fn wait(&mut self, stop: &AtomicBool) -> Option<Vec<PathBuf>> {
+        loop {
+            std::thread::sleep(POLL_INTERVAL);
+            if stop.load(Ordering::Relaxed) {
+                return None;
+            }
+
+            let mut changed = self.changes();
+            if changed.is_empty() {
+                continue;
+            }
+
+            let start = Instant::now();
+            while start.elapsed() < SETTLE_INTERVAL * 5 {
+                std::thread::sleep(SETTLE_INTERVAL);
+                let more = self.changes();
+                if more.is_empty() {
+                    break;
+                }
+                changed.extend(more);
+            }
+
+            changed.sort();
+            changed.dedup();
+            return Some(changed);
+        }
+    }
+}
+
+fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    let mut pending = paths.to_vec();
+
+    while let Some(path) = pending.pop() {
+        let Ok(metadata) = fs::metadata(&path) else {
+            continue;
+        };
+
+        if metadata.is_dir() {
+            pending.extend(
+                fs::read_dir(&path)
+                    .into_iter()
+                    .flatten()
+                    .flatten()
+                    .map(|entry| entry.path()),
+            );
+        } else {
+            snapshot.insert(path, (metadata.modified().ok(), metadata.len()));
+        }
+    }
+
+    snapshot
+}
+
+/*-------------------------------------------------------------------------------------------------
+  Tests
+-------------------------------------------------------------------------------------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let directory =
+            std::env::temp_dir().join(format!("aoc24-watch-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+        directory
+    }
+
+    #[test]
+    fn test_day_cases() {
+        let day_dir = temp_dir("cases");
+        for (file_name, contents) in [
+            ("example.txt", "1"),
+            ("example-part1-answer.txt", "11"),
+            ("example-part2-answer.txt", ""),
+            ("example2-part2.txt", "2"),
+            ("example2-part2-answer.txt", "22"),
+            ("input.txt", "3"),
+            ("input-part2.slow", ""),
+        ] {
+            fs::write(day_dir.join(file_name), contents).unwrap();
+        }
+
+        let parts = [Part::Part1, Part::Part2];
+        assert_eq!(
+            day_cases(&day_dir, &parts, false),
+            [
+                (day_dir.join("example.txt"), vec![Part::Part1]),
+                (day_dir.join("example2-part2.txt"), vec![Part::Part2]),
+                (day_dir.join("input.txt"), vec![Part::Part1]),
+            ]
+        );
+        assert_eq!(
+            day_cases(&day_dir, &parts, true)[2],
+            (day_dir.join("input.txt"), vec![Part::Part1, Part::Part2])
+        );
+
+        fs::remove_dir_all(day_dir).unwrap();
+    }
+
+    #[test]
+    fn test_watcher() {
+        let directory = temp_dir("watcher");
+        let file = directory.join("day1").join("example.txt");
+        fs::create_dir_all(file.parent().unwrap()).unwrap();
+
+        let mut watcher = Watcher::new(vec![directory.clone()]);
+        assert!(watcher.changes().is_empty());
+
+        fs::write(&file, "3   4").unwrap();
+        assert_eq!(watcher.changes(), std::slice::from_ref(&file));
+        assert!(watcher.changes().is_empty());
+
+        fs::write(&file, "3   4\n4   3").unwrap();
+        assert_eq!(watcher.changes(), std::slice::from_ref(&file));
+
+        fs::remove_file(&file).unwrap();
+        assert_eq!(watcher.changes(), [file]);
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+}